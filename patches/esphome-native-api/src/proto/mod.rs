@@ -0,0 +1,406 @@
+// This file is @generated by the generator.
+
+#![allow(missing_docs)]
+
+//! Home of all automatically generated proto modules.
+//! Contains the versioned modules that are activated by default or via the feature flags.
+
+
+#[cfg(feature = "version_2026_1_5")]
+pub mod version_2026_1_5;
+#[cfg(feature = "version_2026_1_5")]
+pub use version_2026_1_5::*;
+#[cfg(feature = "version_2026_1_5")]
+pub(crate) const VERSION: &str = "2026.1.5";
+
+#[cfg(feature = "version_2026_1_4")]
+pub mod version_2026_1_4;
+#[cfg(feature = "version_2026_1_4")]
+pub use version_2026_1_4::*;
+#[cfg(feature = "version_2026_1_4")]
+pub(crate) const VERSION: &str = "2026.1.4";
+
+#[cfg(feature = "version_2026_1_3")]
+pub mod version_2026_1_3;
+#[cfg(feature = "version_2026_1_3")]
+pub use version_2026_1_3::*;
+#[cfg(feature = "version_2026_1_3")]
+pub(crate) const VERSION: &str = "2026.1.3";
+
+#[cfg(feature = "version_2026_1_2")]
+pub mod version_2026_1_2;
+#[cfg(feature = "version_2026_1_2")]
+pub use version_2026_1_2::*;
+#[cfg(feature = "version_2026_1_2")]
+pub(crate) const VERSION: &str = "2026.1.2";
+
+#[cfg(feature = "version_2026_1_1")]
+pub mod version_2026_1_1;
+#[cfg(feature = "version_2026_1_1")]
+pub use version_2026_1_1::*;
+#[cfg(feature = "version_2026_1_1")]
+pub(crate) const VERSION: &str = "2026.1.1";
+
+#[cfg(feature = "version_2026_1_0")]
+pub mod version_2026_1_0;
+#[cfg(feature = "version_2026_1_0")]
+pub use version_2026_1_0::*;
+#[cfg(feature = "version_2026_1_0")]
+pub(crate) const VERSION: &str = "2026.1.0";
+
+#[cfg(feature = "version_2025_12_7")]
+pub mod version_2025_12_7;
+#[cfg(feature = "version_2025_12_7")]
+pub use version_2025_12_7::*;
+#[cfg(feature = "version_2025_12_7")]
+pub(crate) const VERSION: &str = "2025.12.7";
+
+#[cfg(feature = "version_2025_12_6")]
+pub mod version_2025_12_6;
+#[cfg(feature = "version_2025_12_6")]
+pub use version_2025_12_6::*;
+#[cfg(feature = "version_2025_12_6")]
+pub(crate) const VERSION: &str = "2025.12.6";
+
+#[cfg(feature = "version_2025_12_5")]
+pub mod version_2025_12_5;
+#[cfg(feature = "version_2025_12_5")]
+pub use version_2025_12_5::*;
+#[cfg(feature = "version_2025_12_5")]
+pub(crate) const VERSION: &str = "2025.12.5";
+
+#[cfg(feature = "version_2025_12_4")]
+pub mod version_2025_12_4;
+#[cfg(feature = "version_2025_12_4")]
+pub use version_2025_12_4::*;
+#[cfg(feature = "version_2025_12_4")]
+pub(crate) const VERSION: &str = "2025.12.4";
+
+#[cfg(feature = "version_2025_12_3")]
+pub mod version_2025_12_3;
+#[cfg(feature = "version_2025_12_3")]
+pub use version_2025_12_3::*;
+#[cfg(feature = "version_2025_12_3")]
+pub(crate) const VERSION: &str = "2025.12.3";
+
+#[cfg(feature = "version_2025_12_2")]
+pub mod version_2025_12_2;
+#[cfg(feature = "version_2025_12_2")]
+pub use version_2025_12_2::*;
+#[cfg(feature = "version_2025_12_2")]
+pub(crate) const VERSION: &str = "2025.12.2";
+
+#[cfg(feature = "version_2025_12_1")]
+pub mod version_2025_12_1;
+#[cfg(feature = "version_2025_12_1")]
+pub use version_2025_12_1::*;
+#[cfg(feature = "version_2025_12_1")]
+pub(crate) const VERSION: &str = "2025.12.1";
+
+#[cfg(feature = "version_2025_12_0")]
+pub mod version_2025_12_0;
+#[cfg(feature = "version_2025_12_0")]
+pub use version_2025_12_0::*;
+#[cfg(feature = "version_2025_12_0")]
+pub(crate) const VERSION: &str = "2025.12.0";
+
+#[cfg(feature = "version_2025_11_5")]
+pub mod version_2025_11_5;
+#[cfg(feature = "version_2025_11_5")]
+pub use version_2025_11_5::*;
+#[cfg(feature = "version_2025_11_5")]
+pub(crate) const VERSION: &str = "2025.11.5";
+
+#[cfg(feature = "version_2025_11_4")]
+pub mod version_2025_11_4;
+#[cfg(feature = "version_2025_11_4")]
+pub use version_2025_11_4::*;
+#[cfg(feature = "version_2025_11_4")]
+pub(crate) const VERSION: &str = "2025.11.4";
+
+#[cfg(feature = "version_2025_11_3")]
+pub mod version_2025_11_3;
+#[cfg(feature = "version_2025_11_3")]
+pub use version_2025_11_3::*;
+#[cfg(feature = "version_2025_11_3")]
+pub(crate) const VERSION: &str = "2025.11.3";
+
+#[cfg(feature = "version_2025_11_2")]
+pub mod version_2025_11_2;
+#[cfg(feature = "version_2025_11_2")]
+pub use version_2025_11_2::*;
+#[cfg(feature = "version_2025_11_2")]
+pub(crate) const VERSION: &str = "2025.11.2";
+
+#[cfg(feature = "version_2025_11_1")]
+pub mod version_2025_11_1;
+#[cfg(feature = "version_2025_11_1")]
+pub use version_2025_11_1::*;
+#[cfg(feature = "version_2025_11_1")]
+pub(crate) const VERSION: &str = "2025.11.1";
+
+#[cfg(feature = "version_2025_11_0")]
+pub mod version_2025_11_0;
+#[cfg(feature = "version_2025_11_0")]
+pub use version_2025_11_0::*;
+#[cfg(feature = "version_2025_11_0")]
+pub(crate) const VERSION: &str = "2025.11.0";
+
+#[cfg(feature = "version_2025_10_5")]
+pub mod version_2025_10_5;
+#[cfg(feature = "version_2025_10_5")]
+pub use version_2025_10_5::*;
+#[cfg(feature = "version_2025_10_5")]
+pub(crate) const VERSION: &str = "2025.10.5";
+
+#[cfg(feature = "version_2025_10_4")]
+pub mod version_2025_10_4;
+#[cfg(feature = "version_2025_10_4")]
+pub use version_2025_10_4::*;
+#[cfg(feature = "version_2025_10_4")]
+pub(crate) const VERSION: &str = "2025.10.4";
+
+#[cfg(feature = "version_2025_10_3")]
+pub mod version_2025_10_3;
+#[cfg(feature = "version_2025_10_3")]
+pub use version_2025_10_3::*;
+#[cfg(feature = "version_2025_10_3")]
+pub(crate) const VERSION: &str = "2025.10.3";
+
+#[cfg(feature = "version_2025_10_2")]
+pub mod version_2025_10_2;
+#[cfg(feature = "version_2025_10_2")]
+pub use version_2025_10_2::*;
+#[cfg(feature = "version_2025_10_2")]
+pub(crate) const VERSION: &str = "2025.10.2";
+
+#[cfg(feature = "version_2025_10_1")]
+pub mod version_2025_10_1;
+#[cfg(feature = "version_2025_10_1")]
+pub use version_2025_10_1::*;
+#[cfg(feature = "version_2025_10_1")]
+pub(crate) const VERSION: &str = "2025.10.1";
+
+#[cfg(feature = "version_2025_10_0")]
+pub mod version_2025_10_0;
+#[cfg(feature = "version_2025_10_0")]
+pub use version_2025_10_0::*;
+#[cfg(feature = "version_2025_10_0")]
+pub(crate) const VERSION: &str = "2025.10.0";
+
+#[cfg(feature = "version_2025_9_3")]
+pub mod version_2025_9_3;
+#[cfg(feature = "version_2025_9_3")]
+pub use version_2025_9_3::*;
+#[cfg(feature = "version_2025_9_3")]
+pub(crate) const VERSION: &str = "2025.9.3";
+
+#[cfg(feature = "version_2025_9_2")]
+pub mod version_2025_9_2;
+#[cfg(feature = "version_2025_9_2")]
+pub use version_2025_9_2::*;
+#[cfg(feature = "version_2025_9_2")]
+pub(crate) const VERSION: &str = "2025.9.2";
+
+#[cfg(feature = "version_2025_9_1")]
+pub mod version_2025_9_1;
+#[cfg(feature = "version_2025_9_1")]
+pub use version_2025_9_1::*;
+#[cfg(feature = "version_2025_9_1")]
+pub(crate) const VERSION: &str = "2025.9.1";
+
+#[cfg(feature = "version_2025_9_0")]
+pub mod version_2025_9_0;
+#[cfg(feature = "version_2025_9_0")]
+pub use version_2025_9_0::*;
+#[cfg(feature = "version_2025_9_0")]
+pub(crate) const VERSION: &str = "2025.9.0";
+
+#[cfg(feature = "version_2025_8_4")]
+pub mod version_2025_8_4;
+#[cfg(feature = "version_2025_8_4")]
+pub use version_2025_8_4::*;
+#[cfg(feature = "version_2025_8_4")]
+pub(crate) const VERSION: &str = "2025.8.4";
+
+#[cfg(feature = "version_2025_8_3")]
+pub mod version_2025_8_3;
+#[cfg(feature = "version_2025_8_3")]
+pub use version_2025_8_3::*;
+#[cfg(feature = "version_2025_8_3")]
+pub(crate) const VERSION: &str = "2025.8.3";
+
+#[cfg(feature = "version_2025_8_2")]
+pub mod version_2025_8_2;
+#[cfg(feature = "version_2025_8_2")]
+pub use version_2025_8_2::*;
+#[cfg(feature = "version_2025_8_2")]
+pub(crate) const VERSION: &str = "2025.8.2";
+
+#[cfg(feature = "version_2025_8_1")]
+pub mod version_2025_8_1;
+#[cfg(feature = "version_2025_8_1")]
+pub use version_2025_8_1::*;
+#[cfg(feature = "version_2025_8_1")]
+pub(crate) const VERSION: &str = "2025.8.1";
+
+#[cfg(feature = "version_2025_8_0")]
+pub mod version_2025_8_0;
+#[cfg(feature = "version_2025_8_0")]
+pub use version_2025_8_0::*;
+#[cfg(feature = "version_2025_8_0")]
+pub(crate) const VERSION: &str = "2025.8.0";
+
+#[cfg(feature = "version_2025_7_5")]
+pub mod version_2025_7_5;
+#[cfg(feature = "version_2025_7_5")]
+pub use version_2025_7_5::*;
+#[cfg(feature = "version_2025_7_5")]
+pub(crate) const VERSION: &str = "2025.7.5";
+
+#[cfg(feature = "version_2025_7_4")]
+pub mod version_2025_7_4;
+#[cfg(feature = "version_2025_7_4")]
+pub use version_2025_7_4::*;
+#[cfg(feature = "version_2025_7_4")]
+pub(crate) const VERSION: &str = "2025.7.4";
+
+#[cfg(feature = "version_2025_7_3")]
+pub mod version_2025_7_3;
+#[cfg(feature = "version_2025_7_3")]
+pub use version_2025_7_3::*;
+#[cfg(feature = "version_2025_7_3")]
+pub(crate) const VERSION: &str = "2025.7.3";
+
+#[cfg(feature = "version_2025_7_2")]
+pub mod version_2025_7_2;
+#[cfg(feature = "version_2025_7_2")]
+pub use version_2025_7_2::*;
+#[cfg(feature = "version_2025_7_2")]
+pub(crate) const VERSION: &str = "2025.7.2";
+
+#[cfg(feature = "version_2025_7_1")]
+pub mod version_2025_7_1;
+#[cfg(feature = "version_2025_7_1")]
+pub use version_2025_7_1::*;
+#[cfg(feature = "version_2025_7_1")]
+pub(crate) const VERSION: &str = "2025.7.1";
+
+#[cfg(feature = "version_2025_7_0")]
+pub mod version_2025_7_0;
+#[cfg(feature = "version_2025_7_0")]
+pub use version_2025_7_0::*;
+#[cfg(feature = "version_2025_7_0")]
+pub(crate) const VERSION: &str = "2025.7.0";
+
+#[cfg(feature = "version_2025_6_3")]
+pub mod version_2025_6_3;
+#[cfg(feature = "version_2025_6_3")]
+pub use version_2025_6_3::*;
+#[cfg(feature = "version_2025_6_3")]
+pub(crate) const VERSION: &str = "2025.6.3";
+
+#[cfg(feature = "version_2025_6_2")]
+pub mod version_2025_6_2;
+#[cfg(feature = "version_2025_6_2")]
+pub use version_2025_6_2::*;
+#[cfg(feature = "version_2025_6_2")]
+pub(crate) const VERSION: &str = "2025.6.2";
+
+#[cfg(feature = "version_2025_6_1")]
+pub mod version_2025_6_1;
+#[cfg(feature = "version_2025_6_1")]
+pub use version_2025_6_1::*;
+#[cfg(feature = "version_2025_6_1")]
+pub(crate) const VERSION: &str = "2025.6.1";
+
+#[cfg(feature = "version_2025_6_0")]
+pub mod version_2025_6_0;
+#[cfg(feature = "version_2025_6_0")]
+pub use version_2025_6_0::*;
+#[cfg(feature = "version_2025_6_0")]
+pub(crate) const VERSION: &str = "2025.6.0";
+
+#[cfg(feature = "version_2025_5_2")]
+pub mod version_2025_5_2;
+#[cfg(feature = "version_2025_5_2")]
+pub use version_2025_5_2::*;
+#[cfg(feature = "version_2025_5_2")]
+pub(crate) const VERSION: &str = "2025.5.2";
+
+#[cfg(feature = "version_2025_5_1")]
+pub mod version_2025_5_1;
+#[cfg(feature = "version_2025_5_1")]
+pub use version_2025_5_1::*;
+#[cfg(feature = "version_2025_5_1")]
+pub(crate) const VERSION: &str = "2025.5.1";
+
+#[cfg(feature = "version_2025_5_0")]
+pub mod version_2025_5_0;
+#[cfg(feature = "version_2025_5_0")]
+pub use version_2025_5_0::*;
+#[cfg(feature = "version_2025_5_0")]
+pub(crate) const VERSION: &str = "2025.5.0";
+
+#[cfg(feature = "version_2025_4_2")]
+pub mod version_2025_4_2;
+#[cfg(feature = "version_2025_4_2")]
+pub use version_2025_4_2::*;
+#[cfg(feature = "version_2025_4_2")]
+pub(crate) const VERSION: &str = "2025.4.2";
+
+#[cfg(feature = "version_2025_4_1")]
+pub mod version_2025_4_1;
+#[cfg(feature = "version_2025_4_1")]
+pub use version_2025_4_1::*;
+#[cfg(feature = "version_2025_4_1")]
+pub(crate) const VERSION: &str = "2025.4.1";
+
+#[cfg(feature = "version_2025_4_0")]
+pub mod version_2025_4_0;
+#[cfg(feature = "version_2025_4_0")]
+pub use version_2025_4_0::*;
+#[cfg(feature = "version_2025_4_0")]
+pub(crate) const VERSION: &str = "2025.4.0";
+
+#[cfg(feature = "version_2025_3_3")]
+pub mod version_2025_3_3;
+#[cfg(feature = "version_2025_3_3")]
+pub use version_2025_3_3::*;
+#[cfg(feature = "version_2025_3_3")]
+pub(crate) const VERSION: &str = "2025.3.3";
+
+#[cfg(feature = "version_2025_3_2")]
+pub mod version_2025_3_2;
+#[cfg(feature = "version_2025_3_2")]
+pub use version_2025_3_2::*;
+#[cfg(feature = "version_2025_3_2")]
+pub(crate) const VERSION: &str = "2025.3.2";
+
+#[cfg(feature = "version_2025_3_1")]
+pub mod version_2025_3_1;
+#[cfg(feature = "version_2025_3_1")]
+pub use version_2025_3_1::*;
+#[cfg(feature = "version_2025_3_1")]
+pub(crate) const VERSION: &str = "2025.3.1";
+
+#[cfg(feature = "version_2025_3_0")]
+pub mod version_2025_3_0;
+#[cfg(feature = "version_2025_3_0")]
+pub use version_2025_3_0::*;
+#[cfg(feature = "version_2025_3_0")]
+pub(crate) const VERSION: &str = "2025.3.0";
+
+#[cfg(feature = "version_2025_2_2")]
+pub mod version_2025_2_2;
+#[cfg(feature = "version_2025_2_2")]
+pub use version_2025_2_2::*;
+#[cfg(feature = "version_2025_2_2")]
+pub(crate) const VERSION: &str = "2025.2.2";
+
+#[cfg(feature = "version_2025_2_1")]
+pub mod version_2025_2_1;
+#[cfg(feature = "version_2025_2_1")]
+pub use version_2025_2_1::*;
+#[cfg(feature = "version_2025_2_1")]
+pub(crate) const VERSION: &str = "2025.2.1";
@@ -0,0 +1,2 @@
+// This file is @generated by prost-build.
+include!("version_2025_9_0.rs");
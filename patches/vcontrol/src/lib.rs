@@ -0,0 +1,39 @@
+#![warn(missing_debug_implementations)]
+
+mod error;
+pub use crate::error::Error;
+
+pub mod types;
+
+mod command;
+pub use crate::command::Command;
+
+pub(crate) mod mappings;
+
+pub mod commands;
+
+mod access_mode;
+pub use crate::access_mode::AccessMode;
+
+mod optolink;
+pub use crate::optolink::Optolink;
+
+mod protocol;
+pub use crate::protocol::Protocol;
+
+pub mod device;
+pub use crate::device::Device;
+
+mod vcontrol;
+pub use crate::vcontrol::*;
+
+mod value;
+pub use crate::value::{OutputValue, Value};
+
+mod data_type;
+pub use crate::data_type::DataType;
+
+mod parameter;
+pub use crate::parameter::Parameter;
+
+mod conversion;
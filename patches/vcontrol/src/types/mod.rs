@@ -0,0 +1,11 @@
+mod device_id;
+pub use self::device_id::{DeviceId, DeviceIdF0};
+
+mod circuit_time;
+pub use self::circuit_time::{CircuitTime, CircuitTimes};
+
+mod error;
+pub use self::error::Error;
+
+mod date_time;
+pub use self::date_time::{Date, DateTime};
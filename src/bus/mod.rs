@@ -0,0 +1,172 @@
+use std::{
+  cmp::Ordering,
+  collections::BinaryHeap,
+  fmt,
+  ops::{Deref, DerefMut},
+  sync::{
+    Arc,
+    atomic::{self, AtomicU64},
+  },
+};
+
+use tokio::{
+  sync::{mpsc, oneshot},
+  task::JoinHandle,
+};
+use vcontrol::{Device, Protocol, VControl};
+
+/// Priority of a bus request. Pending requests with a higher priority are granted first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+  /// Background polling of all commands.
+  Poll,
+  /// Reads requested by a client.
+  Read,
+  /// Writes requested by a client.
+  Write,
+}
+
+/// Error returned when the bus scheduler has stopped.
+#[derive(Debug)]
+pub struct BusClosed;
+
+impl fmt::Display for BusClosed {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    "bus scheduler stopped".fmt(f)
+  }
+}
+
+struct Request {
+  priority: Priority,
+  seq: u64,
+  reply: oneshot::Sender<Lease>,
+}
+
+impl PartialEq for Request {
+  fn eq(&self, other: &Self) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+impl Eq for Request {}
+
+impl PartialOrd for Request {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Request {
+  /// Orders by priority, then by arrival, so that requests with the same priority are granted in order.
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.priority.cmp(&other.priority).then_with(|| other.seq.cmp(&self.seq))
+  }
+}
+
+/// Exclusive access to the Optolink, returned to the bus scheduler when dropped.
+///
+/// Multiple commands run using the same lease are not interleaved with other requests.
+pub struct Lease {
+  vcontrol: Option<VControl>,
+  release: Option<oneshot::Sender<VControl>>,
+}
+
+impl Deref for Lease {
+  type Target = VControl;
+
+  fn deref(&self) -> &Self::Target {
+    self.vcontrol.as_ref().unwrap()
+  }
+}
+
+impl DerefMut for Lease {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    self.vcontrol.as_mut().unwrap()
+  }
+}
+
+impl Drop for Lease {
+  fn drop(&mut self) {
+    if let (Some(vcontrol), Some(release)) = (self.vcontrol.take(), self.release.take()) {
+      // The scheduler may already have stopped.
+      let _ = release.send(vcontrol);
+    }
+  }
+}
+
+/// Handle for sending requests to the bus scheduler.
+#[derive(Clone)]
+pub struct Bus {
+  tx: mpsc::UnboundedSender<Request>,
+  seq: Arc<AtomicU64>,
+  device: &'static Device,
+  protocol: Protocol,
+}
+
+impl Bus {
+  /// Returns the connected device, without waiting for the bus.
+  pub fn device(&self) -> &'static Device {
+    self.device
+  }
+
+  /// Returns the negotiated protocol, without waiting for the bus.
+  pub fn protocol(&self) -> Protocol {
+    self.protocol
+  }
+
+  pub fn is_closed(&self) -> bool {
+    self.tx.is_closed()
+  }
+
+  /// Waits until the bus is granted with the given priority.
+  pub async fn acquire(&self, priority: Priority) -> Result<Lease, BusClosed> {
+    let (reply, lease) = oneshot::channel();
+    let seq = self.seq.fetch_add(1, atomic::Ordering::Relaxed);
+    self.tx.send(Request { priority, seq, reply }).map_err(|_| BusClosed)?;
+    lease.await.map_err(|_| BusClosed)
+  }
+}
+
+async fn schedule(mut vcontrol: VControl, mut rx: mpsc::UnboundedReceiver<Request>) {
+  log::info!("Bus scheduler started.");
+
+  let mut queue = BinaryHeap::new();
+
+  loop {
+    if queue.is_empty() {
+      match rx.recv().await {
+        Some(request) => queue.push(request),
+        None => break,
+      }
+    }
+    while let Ok(request) = rx.try_recv() {
+      queue.push(request);
+    }
+
+    let Some(request) = queue.pop() else { continue };
+
+    let (release, released) = oneshot::channel();
+    let lease = Lease { vcontrol: Some(vcontrol), release: Some(release) };
+    // If the requester is gone, the lease is dropped right away, which releases it.
+    let _ = request.reply.send(lease);
+
+    vcontrol = match released.await {
+      Ok(vcontrol) => vcontrol,
+      Err(_) => {
+        log::error!("Bus lease was never released.");
+        break;
+      },
+    };
+  }
+
+  log::info!("Bus scheduler stopped.");
+}
+
+/// Starts a task owning the Optolink which grants access to it in order of priority.
+///
+/// The bus is closed once the returned task is aborted or all handles are dropped.
+pub fn start(mut vcontrol: VControl) -> (Bus, JoinHandle<()>) {
+  let (tx, rx) = mpsc::unbounded_channel();
+  let bus = Bus { tx, seq: Arc::new(AtomicU64::new(0)), device: vcontrol.device(), protocol: vcontrol.protocol() };
+  (bus, tokio::spawn(schedule(vcontrol, rx)))
+}
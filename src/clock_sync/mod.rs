@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use tokio::time;
+use vcontrol::{VControl, Value};
+
+use crate::bus::{Bus, Priority};
+use crate::time_zone::TimeZone;
+
+const CLOCK_COMMAND: &str = "NRF_Uhrzeit";
+
+/// How often the controller clock is compared against the system clock.
+const SYNC_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Maximum drift in seconds before the controller clock is corrected.
+const MAX_DRIFT: i64 = 30;
+
+async fn sync(vcontrol: &mut VControl, time_zone: TimeZone) -> Result<(), vcontrol::Error> {
+  let controller_time = match vcontrol.get(CLOCK_COMMAND).await?.value {
+    Value::DateTime(date_time) => Some(date_time),
+    value => {
+      log::warn!("Unexpected value for {CLOCK_COMMAND}: {value:?}");
+      None
+    },
+  };
+
+  let now = time_zone.now();
+  let drift = controller_time
+    .as_ref()
+    .and_then(|controller_time| time_zone.unix_timestamp(controller_time))
+    .zip(time_zone.unix_timestamp(&now))
+    .map(|(controller_time, now)| controller_time - now);
+
+  match drift {
+    Some(drift) if drift.abs() <= MAX_DRIFT => {
+      log::debug!("Controller clock drift is {drift} s.");
+      return Ok(());
+    },
+    Some(drift) => log::info!("Controller clock drift is {drift} s, setting controller clock to {now}."),
+    None => log::info!("Controller clock is invalid ({controller_time:?}), setting controller clock to {now}."),
+  }
+
+  vcontrol.set(CLOCK_COMMAND, Value::DateTime(now)).await
+}
+
+/// Periodically synchronizes the controller clock with the system clock.
+pub async fn clock_sync(bus: Bus, time_zone: TimeZone) {
+  log::info!("Clock sync started using {time_zone}.");
+
+  let mut interval = time::interval(SYNC_INTERVAL);
+  interval.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+
+  loop {
+    interval.tick().await;
+
+    if bus.device().command(CLOCK_COMMAND).is_none_or(|command| !command.access_mode().is_write()) {
+      log::info!("Device does not support setting the clock.");
+      break;
+    }
+
+    let Ok(mut vcontrol) = bus.acquire(Priority::Write).await else { break };
+
+    if let Err(err) = sync(&mut vcontrol, time_zone).await {
+      log::error!("Failed to sync controller clock: {err}");
+    }
+  }
+
+  log::info!("Clock sync stopped.");
+}
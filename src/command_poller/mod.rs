@@ -1,114 +1,435 @@
-use std::{collections::HashMap, mem, sync::Arc};
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::ops::Range;
+use std::time::Duration;
 
+use chrono::Utc;
 use itertools::Itertools;
 use rangemap::RangeMap;
-use tokio::sync::broadcast::{self, Receiver, error::SendError};
+use tokio::time;
 
-use vcontrol::{Command, VControl, Value};
+use vcontrol::{Command, Value};
 
-pub async fn poll_thread(
-  vcontrol: VControl,
-) -> (
-  Arc<tokio::sync::Mutex<VControl>>,
-  Receiver<(&'static str, Value)>,
-  impl Future<Output = Result<(), std::io::Error>>,
-  HashMap<&'static str, &'static Command>,
-) {
-  let mut commands = HashMap::<&'static str, &'static Command>::new();
+use crate::bus::{Bus, BusClosed, Priority};
+use crate::command_poller::planner::{CostModel, Plan, plan_ranges};
+use crate::command_poller::quarantine::Quarantine;
+use crate::command_poller::refresh::{Requests, refresher};
+use crate::command_poller::store::{Publisher, store};
 
-  for (command_name, command) in vcontrol::commands::system_commands() {
-    commands.insert(command_name, command);
+mod planner;
+mod quarantine;
+pub use quarantine::QUARANTINED_COMMANDS;
+mod refresh;
+pub use refresh::Refresher;
+mod sample;
+pub use sample::{CYCLE_END, Sample};
+mod store;
+pub use store::{Store, Updates};
+mod subscriptions;
+pub use subscriptions::Subscriptions;
+
+/// Number of times a failed read is retried.
+const MAX_RETRIES: u32 = 2;
+
+/// Delay before the first retry, doubled for every further retry.
+const RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// Time between poll cycles while only background consumers are subscribed.
+const IDLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Plans the ranges for reading all subscribed commands.
+fn plan(
+  readable_commands: &[(&'static str, &'static Command)],
+  subscribed: &BTreeMap<&'static str, usize>,
+  quarantine: &Quarantine,
+  cost_model: &CostModel,
+) -> RangeMap<u16, Vec<(&'static str, &'static Command)>> {
+  let consumed_commands = readable_commands
+    .iter()
+    .filter(|(command_name, _)| subscribed.contains_key(command_name) && !quarantine.contains(command_name))
+    .copied()
+    .collect::<Vec<_>>();
+  let Plan { ranges: command_ranges, skipped, cost } =
+    plan_ranges(&consumed_commands, cost_model, &quarantine.failing_regions());
+
+  for command_name in skipped {
+    log::warn!("Skipping command {command_name}, its range is known to fail.");
+  }
+  match cost {
+    Some(cost) => log::debug!("Estimated poll cycle time: {cost:?}"),
+    None => log::warn!("Failed to estimate poll cycle time, reading overlapping commands separately."),
   }
 
-  for (command_name, command) in vcontrol.device().commands() {
-    commands.insert(command_name, command);
+  log::info!(
+    "Polling {} of {} readable commands in {} ranges.",
+    consumed_commands.len(),
+    readable_commands.len(),
+    command_ranges.len()
+  );
+  for command_range in command_ranges.iter().map(|(range, _)| range) {
+    log::debug!("{command_range:#04X?} {command_range:#05?}");
   }
 
-  let vcontrol = Arc::new(tokio::sync::Mutex::new(vcontrol));
+  let range_lengths = command_ranges.iter().map(|(range, _)| range).counts_by(|range| range.end - range.start);
+  log::debug!("range_lengths: {range_lengths:?}");
 
-  let mut commands_sorted = commands
-    .iter()
-    .filter(|(_, command)| command.access_mode().is_read())
-    .map(|(&k, &v)| (k, v))
-    .collect::<Vec<(&'static str, &'static Command)>>();
-  commands_sorted.sort_by_key(|(_, command)| (command.addr(), command.block_len()));
+  command_ranges
+}
+
+/// Reads the given range into `buffer`, retrying with increasing delays. The bus is released between attempts.
+async fn read_range(
+  bus: &Bus,
+  priority: Priority,
+  range: Range<u16>,
+  buffer: &mut Vec<u8>,
+) -> Result<io::Result<()>, BusClosed> {
+  let mut delay = RETRY_DELAY;
+
+  for attempt in 0.. {
+    let res = {
+      let mut vcontrol = bus.acquire(priority).await?;
+      let protocol = vcontrol.protocol();
+      buffer.resize((range.end - range.start) as usize, 0);
+      protocol.get(vcontrol.optolink(), range.start, buffer).await
+    };
+
+    match res {
+      Err(err) if attempt < MAX_RETRIES => {
+        log::debug!("Failed to read range {range:#06X?}, retrying in {delay:?}: {err}");
+        time::sleep(delay).await;
+        delay *= 2;
+      },
+      res => return Ok(res),
+    }
+  }
+
+  unreachable!()
+}
 
-  let mut command_ranges = RangeMap::new();
-  let mut current_range = None;
+/// Decodes and publishes the values of the given commands from `buffer`, which contains the values read from `range`.
+///
+/// Returns whether any command was quarantined or released from quarantine.
+fn publish_values(
+  commands: &[(&'static str, &'static Command)],
+  range: Range<u16>,
+  buffer: &[u8],
+  cycle: u64,
+  quarantine: &mut Quarantine,
+  publisher: &Publisher,
+) -> bool {
+  let read_at = Utc::now();
+  let mut quarantined = false;
 
-  const MAX_BLOCK_LEN: usize = 119;
+  for &(command_name, command) in commands {
+    let start = (command.addr() - range.start) as usize;
+    let bytes = &buffer[start..(start + command.block_len())];
 
-  for (command_name, command) in &commands_sorted {
-    let addr = command.addr();
-    let block_len = command.block_len() as u16;
+    match command.deserialize(bytes) {
+      Ok(value) => {
+        quarantined |= quarantine.succeeded(command_name);
+        publisher.publish(Sample { command_name, value, read_at, cycle, range: Some(range.clone()), previous: None });
+      },
+      Err(err) => quarantined |= quarantine.failed(command_name, command, err),
+    }
+  }
 
-    let (range, range_commands) =
-      current_range.get_or_insert_with(|| (addr..(addr + block_len), vec![(*command_name, *command)]));
+  quarantined
+}
 
-    let combined_len = (addr + block_len) - range.start;
+/// Reads the commands of a failing range one by one to isolate the failing commands, returning whether the plan
+/// needs to change.
+async fn isolate_failures(
+  bus: &Bus,
+  range: Range<u16>,
+  commands: &[(&'static str, &'static Command)],
+  buffer: &mut Vec<u8>,
+  cycle: u64,
+  quarantine: &mut Quarantine,
+  publisher: &Publisher,
+) -> Result<bool, BusClosed> {
+  let mut changed = false;
+  let mut all_read = true;
+  for &(command_name, command) in commands {
+    let command_range = command.addr()..(command.addr() + command.block_len() as u16);
 
-    if combined_len as usize <= MAX_BLOCK_LEN {
-      range.end = range.end.max(addr + block_len);
-      range_commands.push((*command_name, *command));
-    } else {
-      let range = mem::replace(range, addr..(addr + block_len));
-      let range_commands = mem::replace(range_commands, vec![(*command_name, *command)]);
-      command_ranges.insert(range, range_commands);
+    match read_range(bus, Priority::Poll, command_range.clone(), buffer).await? {
+      Ok(()) => {
+        let commands = [(command_name, command)];
+        changed |= publish_values(&commands, command_range, buffer, cycle, quarantine, publisher);
+      },
+      Err(err) => {
+        all_read = false;
+        changed |= quarantine.failed(command_name, command, err);
+      },
     }
   }
-  if let Some((range, commands)) = current_range.take() {
-    command_ranges.insert(range, commands);
+
+  // Only the unused regions in between failed, so avoid them from now on.
+  if all_read {
+    changed |= quarantine.failed_gaps(range, commands);
   }
 
-  log::debug!("commands: {}, command_ranges: {}", commands_sorted.len(), command_ranges.len());
-  for command_range in command_ranges.iter().map(|(range, _)| range) {
-    log::debug!("{command_range:#04X?} {command_range:#05?}");
+  Ok(changed)
+}
+
+/// Probes the quarantined commands and failing regions which are due, returning whether any of them was released.
+async fn probe(
+  bus: &Bus,
+  readable_commands: &HashMap<&'static str, &'static Command>,
+  buffer: &mut Vec<u8>,
+  cycle: u64,
+  quarantine: &mut Quarantine,
+  publisher: &Publisher,
+) -> Result<bool, BusClosed> {
+  let (command_names, regions) = quarantine.due_probes();
+  let mut released = false;
+
+  for command_name in command_names {
+    let command = readable_commands[command_name];
+    let range = command.addr()..(command.addr() + command.block_len() as u16);
+
+    let res = match read_range(bus, Priority::Poll, range.clone(), buffer).await? {
+      Ok(()) => command.deserialize(buffer).map_err(|err| err.to_string()),
+      Err(err) => Err(err.to_string()),
+    };
+
+    match res {
+      Ok(value) => {
+        released |= quarantine.succeeded(command_name);
+        let read_at = Utc::now();
+        publisher.publish(Sample { command_name, value, read_at, cycle, range: Some(range), previous: None });
+      },
+      Err(err) => quarantine.probe_failed(command_name, err),
+    }
   }
 
-  let range_lengths = command_ranges.iter().map(|(range, _)| range).counts_by(|range| range.end - range.start);
-  log::debug!("range_lengths: {range_lengths:?}");
+  for region in regions {
+    match read_range(bus, Priority::Poll, region.clone(), buffer).await? {
+      Ok(()) => {
+        quarantine.region_succeeded(&region);
+        released = true;
+      },
+      Err(err) => quarantine.region_failed(&region, err),
+    }
+  }
+
+  Ok(released)
+}
+
+/// Reads the commands requested via [`Refresher`] in a cycle of their own, returning whether the plan needs to change.
+///
+/// The commands are read in planned ranges with [`Priority::Read`], acquiring the bus for each range. Quarantined
+/// commands are read as well, so that they are released if they succeed.
+async fn refresh(
+  bus: &Bus,
+  requests: &mut Requests,
+  cost_model: &CostModel,
+  last_cycle: &mut u64,
+  buffer: &mut Vec<u8>,
+  quarantine: &mut Quarantine,
+  publisher: &Publisher,
+) -> Result<bool, BusClosed> {
+  let commands = requests.take();
+  if commands.is_empty() {
+    return Ok(false);
+  }
 
-  let (tx, rx) = broadcast::channel((MAX_BLOCK_LEN * 2).next_power_of_two());
+  *last_cycle += 1;
+  let cycle = *last_cycle;
 
-  let vcontrol_weak = Arc::downgrade(&vcontrol);
-  let poll_thread = async move {
+  let Plan { ranges, skipped, .. } = plan_ranges(&commands, cost_model, &quarantine.failing_regions());
+  for command_name in skipped {
+    log::warn!("Cannot refresh command {command_name}, its range is known to fail.");
+  }
+
+  let mut changed = false;
+  for (range, commands) in ranges.iter() {
+    match read_range(bus, Priority::Read, range.clone(), buffer).await? {
+      Ok(()) => changed |= publish_values(commands, range.clone(), buffer, cycle, quarantine, publisher),
+      Err(err) => log::error!("Failed to refresh range {range:#06X?}: {err}"),
+    }
+  }
+
+  let read_at = Utc::now();
+  publisher.publish(Sample {
+    command_name: CYCLE_END,
+    value: Value::Empty,
+    read_at,
+    cycle,
+    range: None,
+    previous: None,
+  });
+
+  Ok(changed)
+}
+
+/// Checks whether the controller responds at all by reading its device ID.
+async fn check_connection(bus: &Bus) -> Result<Result<(), vcontrol::Error>, BusClosed> {
+  let mut vcontrol = bus.acquire(Priority::Poll).await?;
+  Ok(vcontrol.get("device_id").await.map(drop))
+}
+
+/// Polls all commands which are subscribed via `subscriptions`, re-planning whenever the subscribed commands change.
+///
+/// Commands are polled continuously while any listener is subscribed, otherwise only every [`IDLE_INTERVAL`].
+/// Commands requested via the returned [`Refresher`] are read ahead of the remaining ranges of the current cycle.
+/// Commands which keep failing are quarantined, see [`Quarantine`]. Fails if no range can be read and the controller
+/// does not respond to a connection check either.
+pub async fn poll_thread(
+  bus: Bus,
+  subscriptions: Subscriptions,
+) -> (Store, Refresher, impl Future<Output = Result<(), std::io::Error>>, HashMap<&'static str, &'static Command>) {
+  let mut commands = HashMap::<&'static str, &'static Command>::new();
+
+  for (command_name, command) in vcontrol::commands::system_commands() {
+    commands.insert(command_name, command);
+  }
+
+  for (command_name, command) in bus.device().commands() {
+    commands.insert(command_name, command);
+  }
+
+  let readable_commands = commands
+    .iter()
+    .filter(|(_, command)| command.access_mode().is_read())
+    .map(|(&k, &v)| (k, v))
+    .collect::<Vec<(&'static str, &'static Command)>>();
+
+  let cost_model = CostModel::new(bus.protocol(), &readable_commands);
+
+  let (publisher, store) = store();
+  let readable = readable_commands.iter().copied().collect::<HashMap<_, _>>();
+  let (refresher, mut requests) = refresher(readable.clone());
+
+  let poll_loop = async move {
     log::info!("Poll thread started.");
 
+    // Since `subscriptions` is moved into this future, `subscribed` is never closed.
+    let mut subscribed = subscriptions.watch();
+
+    let mut quarantine = Quarantine::default();
+    let mut last_cycle = 0;
     let mut buffer = Vec::new();
     'outer: loop {
-      for (range, commands) in command_ranges.iter() {
-        let Some(vcontrol) = vcontrol_weak.upgrade() else { break 'outer };
-        let mut vcontrol = vcontrol.lock().await;
+      let (command_ranges, mut idle) = {
+        let subscribed = subscribed.borrow_and_update();
+        (plan(&readable_commands, &subscribed.commands, &quarantine, &cost_model), subscribed.listeners == 0)
+      };
 
-        let protocol = vcontrol.protocol();
-        buffer.resize((range.end - range.start) as usize, 0);
-        protocol.get(vcontrol.optolink(), range.start, &mut buffer).await?;
+      if command_ranges.is_empty() {
+        if quarantine.is_empty() {
+          log::info!("No commands subscribed, waiting for subscriptions.");
+          tokio::select! {
+            res = subscribed.changed() => {
+              if res.is_err() {
+                break;
+              }
+            },
+            () = requests.pending() => {
+              let res =
+                refresh(&bus, &mut requests, &cost_model, &mut last_cycle, &mut buffer, &mut quarantine, &publisher);
+              if res.await.is_err() {
+                break;
+              }
+            },
+          }
+          continue;
+        }
 
-        let start_addr = commands[0].1.addr();
+        log::info!("No commands to poll, probing quarantined commands every {IDLE_INTERVAL:?}.");
+        idle = true;
+      } else if idle {
+        log::info!("No listeners subscribed, polling every {IDLE_INTERVAL:?}.");
+      }
 
-        for (command_name, command) in commands {
-          let addr = command.addr();
-          let block_len = command.block_len();
+      loop {
+        last_cycle += 1;
+        let cycle = last_cycle;
+        let mut replan = false;
 
-          let start = (addr - start_addr) as usize;
+        let mut failed_ranges = vec![];
+        let mut read_any = false;
+        for (range, commands) in command_ranges.iter() {
+          // Requested commands are read ahead of the remaining ranges.
+          if requests.is_pending() {
+            let res =
+              refresh(&bus, &mut requests, &cost_model, &mut last_cycle, &mut buffer, &mut quarantine, &publisher);
+            let Ok(changed) = res.await else { break 'outer };
+            replan |= changed;
+          }
 
-          let bytes = &buffer[start..(start + block_len)];
+          let Ok(res) = read_range(&bus, Priority::Poll, range.clone(), &mut buffer).await else { break 'outer };
 
-          let value = match command.deserialize(bytes) {
-            Ok(value) => value,
-            Err(err) => {
-              log::error!("Failed to deserialize value for command {command_name}: {}", err);
-              continue;
+          match res {
+            Ok(()) => {
+              read_any = true;
+              replan |= publish_values(commands, range.clone(), &buffer, cycle, &mut quarantine, &publisher);
             },
-          };
+            Err(err) => failed_ranges.push((range.clone(), commands, err)),
+          }
+        }
 
-          match tx.send((*command_name, value)) {
-            Ok(_receivers) => continue,
-            Err(SendError((command_name, value))) => {
-              log::error!("Failed to send value for command {command_name}: {value:?}");
-              break 'outer;
-            },
+        // Don't blame the commands if the controller is not responding at all.
+        if !read_any && !failed_ranges.is_empty() {
+          let Ok(res) = check_connection(&bus).await else { break 'outer };
+          if let Err(err) = res {
+            return Err(io::Error::other(format!("Failed to read any range, controller is not responding: {err}")));
+          }
+        }
+
+        for (range, commands, err) in failed_ranges {
+          if let [(command_name, command)] = commands[..] {
+            replan |= quarantine.failed(command_name, command, err);
+            continue;
+          }
+
+          log::debug!("Failed to read range {range:#06X?}, reading commands separately: {err}");
+          let res = isolate_failures(&bus, range, commands, &mut buffer, cycle, &mut quarantine, &publisher).await;
+          let Ok(changed) = res else { break 'outer };
+          replan |= changed;
+        }
+
+        let res = probe(&bus, &readable, &mut buffer, cycle, &mut quarantine, &publisher).await;
+        let Ok(released) = res else { break 'outer };
+        replan |= released;
+
+        let read_at = Utc::now();
+        let value = quarantine.value();
+        publisher.publish(Sample {
+          command_name: QUARANTINED_COMMANDS,
+          value,
+          read_at,
+          cycle,
+          range: None,
+          previous: None,
+        });
+        let value = Value::Empty;
+        publisher.publish(Sample { command_name: CYCLE_END, value, read_at, cycle, range: None, previous: None });
+
+        if replan {
+          continue 'outer;
+        }
+
+        if idle {
+          // Resume immediately when subscriptions change, and read requested commands while waiting.
+          let next_cycle = time::Instant::now() + IDLE_INTERVAL;
+          loop {
+            tokio::select! {
+              () = time::sleep_until(next_cycle) => break,
+              _ = subscribed.changed() => continue 'outer,
+              () = requests.pending() => {
+                let res =
+                  refresh(&bus, &mut requests, &cost_model, &mut last_cycle, &mut buffer, &mut quarantine, &publisher);
+                match res.await {
+                  Ok(true) => continue 'outer,
+                  Ok(false) => (),
+                  Err(BusClosed) => break 'outer,
+                }
+              },
+            }
           }
+        } else if subscribed.has_changed().unwrap_or(false) {
+          continue 'outer;
         }
       }
     }
@@ -116,5 +437,5 @@ pub async fn poll_thread(
     Ok(())
   };
 
-  (vcontrol, rx, poll_thread, commands)
+  (store, refresher, poll_loop, commands)
 }
@@ -0,0 +1,308 @@
+use std::{ops::Range, time::Duration};
+
+use rangemap::RangeMap;
+use vcontrol::{Command, Protocol};
+
+/// Time of transferring a single byte at 4800 baud with 8 data bits, even parity and 2 stop bits.
+const BYTE_TIME: Duration = Duration::from_micros(12 * 1_000_000 / 4800);
+
+/// Estimated cost of reading a block of memory from the controller.
+///
+/// The figures are estimates derived from the protocols as implemented by `vcontrol`, they have not been measured.
+#[derive(Debug, Clone, Copy)]
+pub struct CostModel {
+  /// Fixed cost of each request, i.e. framing, acknowledgement and the controller's response delay.
+  pub request_overhead: Duration,
+  /// Cost of each byte read.
+  pub byte_cost: Duration,
+  /// Maximum number of bytes which can be read with a single request.
+  pub max_block_len: usize,
+}
+
+impl CostModel {
+  /// Returns the cost model for the given protocol and the commands of the connected device.
+  pub fn new<C: Block>(protocol: Protocol, commands: &[(&str, &C)]) -> Self {
+    match protocol {
+      // Each request resets the connection and waits for the next `SYNC` byte, followed by a 5 byte request.
+      // The 100 ms until `SYNC` are an assumption. Since a response containing `SYNC` is repeated, the assumed
+      // overhead is deliberately high to avoid reading unused bytes.
+      Protocol::Vs1 => Self {
+        request_overhead: BYTE_TIME * 5 + Duration::from_millis(100),
+        byte_cost: BYTE_TIME,
+        max_block_len: Self::max_block_len(protocol, commands),
+      },
+      // An 8 byte request telegram, an acknowledgement byte and an 8 byte response telegram without the payload.
+      // The response delay of 30 ms is an assumption.
+      Protocol::Vs2 => Self {
+        request_overhead: BYTE_TIME * 17 + Duration::from_millis(30),
+        byte_cost: BYTE_TIME,
+        max_block_len: Self::max_block_len(protocol, commands),
+      },
+    }
+  }
+
+  /// Returns the maximum number of bytes read with a single request.
+  ///
+  /// With VS1, the length is sent as a single byte, but reads of more than 119 bytes were seen to get stuck. Larger
+  /// blocks are only read if the device has a command reading at least as many bytes at once. With VS2, the telegram
+  /// length is a single byte which includes the 5 byte header.
+  fn max_block_len<C: Block>(protocol: Protocol, commands: &[(&str, &C)]) -> usize {
+    let (default, limit) = match protocol {
+      Protocol::Vs1 => (119, usize::from(u8::MAX)),
+      Protocol::Vs2 => (usize::from(u8::MAX) - 5, usize::from(u8::MAX) - 5),
+    };
+
+    let max_command_len = commands.iter().map(|(_, command)| command.block_len()).max().unwrap_or_default();
+    max_command_len.clamp(default, limit)
+  }
+
+  /// Returns the estimated time of reading a block with the given length.
+  pub fn cost(&self, len: usize) -> Duration {
+    self.request_overhead.saturating_add(self.byte_cost.saturating_mul(len as u32))
+  }
+}
+
+/// A block of memory read by a command.
+pub trait Block {
+  fn addr(&self) -> u16;
+  fn block_len(&self) -> usize;
+
+  fn range(&self) -> Range<u16> {
+    self.addr()..(self.addr() + self.block_len() as u16)
+  }
+}
+
+impl Block for Command {
+  fn addr(&self) -> u16 {
+    Command::addr(self)
+  }
+
+  fn block_len(&self) -> usize {
+    Command::block_len(self)
+  }
+}
+
+/// Ranges planned by [`plan_ranges`].
+#[derive(Debug)]
+pub struct Plan<'a, C> {
+  pub ranges: RangeMap<u16, Vec<(&'a str, &'a C)>>,
+  /// Commands which are not read since they overlap an `avoid` region.
+  pub skipped: Vec<&'a str>,
+  /// Estimated time of reading all ranges, or `None` if no cost could be estimated, in which case each group of
+  /// overlapping commands is read separately.
+  pub cost: Option<Duration>,
+}
+
+fn overlaps(range: &Range<u16>, regions: &[Range<u16>]) -> bool {
+  regions.iter().any(|region| range.start < region.end && region.start < range.end)
+}
+
+/// Groups commands into address ranges which are read with a single request each, minimising the
+/// total estimated transfer time.
+///
+/// Commands are only grouped if the resulting range fits into a single request. Unused bytes between
+/// commands are only read if they do not overlap any of the `avoid` regions. Commands which overlap an
+/// `avoid` region themselves are skipped.
+pub fn plan_ranges<'a, C: Block + PartialEq>(
+  commands: &[(&'a str, &'a C)],
+  cost_model: &CostModel,
+  avoid: &[Range<u16>],
+) -> Plan<'a, C> {
+  let (mut commands, skipped): (Vec<_>, Vec<_>) =
+    commands.iter().copied().partition(|(_, command)| !overlaps(&command.range(), avoid));
+  let skipped = skipped.into_iter().map(|(command_name, _)| command_name).collect();
+  commands.sort_by_key(|(_, command)| (command.addr(), command.block_len()));
+
+  let n = commands.len();
+
+  // A range can only start at a command which does not overlap any previous command, otherwise the
+  // ranges would overlap.
+  let mut can_start = vec![true; n];
+  let mut prefix_end = 0;
+  for (i, (_, command)) in commands.iter().enumerate() {
+    can_start[i] = i == 0 || command.addr() >= prefix_end;
+    prefix_end = prefix_end.max(command.range().end);
+  }
+
+  // `best[i]` is the minimal cost of reading the first `i` commands, with the last range starting at
+  // command `start[i]`.
+  let mut best = vec![Duration::MAX; n + 1];
+  let mut start = vec![0; n + 1];
+  best[0] = Duration::ZERO;
+
+  for i in 0..n {
+    if !can_start[i] || best[i] == Duration::MAX {
+      continue;
+    }
+
+    let range_start = commands[i].1.addr();
+    let mut range_end = range_start;
+
+    for (j, (_, command)) in commands.iter().enumerate().skip(i) {
+      let addr = command.addr();
+      let gap = range_end..addr;
+      if j > i && !gap.is_empty() && overlaps(&gap, avoid) {
+        break;
+      }
+
+      range_end = range_end.max(command.range().end);
+      let len = (range_end - range_start) as usize;
+
+      // Commands overlapping a previous command must be read in the same range, even if it exceeds the maximum.
+      if j > i && can_start[j] && len > cost_model.max_block_len {
+        break;
+      }
+
+      // The next range cannot start at the next command, so this range must be extended.
+      if j + 1 < n && !can_start[j + 1] {
+        continue;
+      }
+
+      let cost = best[i].saturating_add(cost_model.cost(len));
+      if cost < best[j + 1] {
+        best[j + 1] = cost;
+        start[j + 1] = i;
+      }
+    }
+  }
+
+  let cost = (best[n] != Duration::MAX).then_some(best[n]);
+  if cost.is_none() {
+    // Fall back to the smallest possible ranges, i.e. one for each group of overlapping commands.
+    let mut end = n;
+    for i in (0..n).rev() {
+      if can_start[i] {
+        start[end] = i;
+        end = i;
+      }
+    }
+  }
+
+  let mut ranges = RangeMap::new();
+  let mut end = n;
+  while end > 0 {
+    let group = &commands[start[end]..end];
+    let range_start = group[0].1.addr();
+    let range_end = group.iter().map(|(_, command)| command.range().end).max().unwrap();
+    ranges.insert(range_start..range_end, group.to_vec());
+    end = start[end];
+  }
+
+  Plan { ranges, skipped, cost }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Debug, PartialEq)]
+  struct TestBlock(Range<u16>);
+
+  impl Block for TestBlock {
+    fn addr(&self) -> u16 {
+      self.0.start
+    }
+
+    fn block_len(&self) -> usize {
+      self.0.len()
+    }
+  }
+
+  const COST_MODEL: CostModel =
+    CostModel { request_overhead: Duration::from_millis(10), byte_cost: Duration::from_millis(1), max_block_len: 8 };
+
+  type Ranges<'a> = Vec<(Range<u16>, Vec<&'a str>)>;
+
+  fn plan<'a>(
+    commands: &[(&'a str, &'a TestBlock)],
+    cost_model: &CostModel,
+    avoid: &[Range<u16>],
+  ) -> (Ranges<'a>, Plan<'a, TestBlock>) {
+    let plan = plan_ranges(commands, cost_model, avoid);
+    let ranges = plan
+      .ranges
+      .iter()
+      .map(|(range, commands)| (range.clone(), commands.iter().map(|&(command_name, _)| command_name).collect()))
+      .collect();
+    (ranges, plan)
+  }
+
+  #[test]
+  fn merges_small_gaps() {
+    let (a, b) = (TestBlock(0..2), TestBlock(4..6));
+    let (ranges, plan) = plan(&[("b", &b), ("a", &a)], &COST_MODEL, &[]);
+
+    assert_eq!(ranges, [(0..6, vec!["a", "b"])]);
+    assert_eq!(plan.cost, Some(Duration::from_millis(16)));
+  }
+
+  #[test]
+  fn splits_large_gaps() {
+    let (a, b) = (TestBlock(0..2), TestBlock(6..8));
+    let cost_model = CostModel { request_overhead: Duration::from_millis(2), ..COST_MODEL };
+    let (ranges, plan) = plan(&[("a", &a), ("b", &b)], &cost_model, &[]);
+
+    assert_eq!(ranges, [(0..2, vec!["a"]), (6..8, vec!["b"])]);
+    assert_eq!(plan.cost, Some(Duration::from_millis(8)));
+  }
+
+  #[test]
+  fn respects_max_block_len() {
+    let (a, b, c) = (TestBlock(0..2), TestBlock(3..5), TestBlock(7..10));
+    let (ranges, plan) = plan(&[("a", &a), ("b", &b), ("c", &c)], &COST_MODEL, &[]);
+
+    // Reading all commands at once would exceed the maximum block length.
+    assert_eq!(ranges, [(0..5, vec!["a", "b"]), (7..10, vec!["c"])]);
+    assert_eq!(plan.cost, Some(Duration::from_millis(28)));
+  }
+
+  #[test]
+  fn reads_overlapping_commands_together() {
+    let (a, b, c, d) = (TestBlock(0..6), TestBlock(2..4), TestBlock(5..12), TestBlock(12..14));
+    let (ranges, _) = plan(&[("a", &a), ("b", &b), ("c", &c), ("d", &d)], &COST_MODEL, &[]);
+
+    // `c` overlaps `a`, so both are read in a single range exceeding the maximum block length.
+    assert_eq!(ranges, [(0..12, vec!["a", "b", "c"]), (12..14, vec!["d"])]);
+  }
+
+  #[test]
+  fn avoids_regions() {
+    let (a, b, c) = (TestBlock(0..2), TestBlock(3..5), TestBlock(10..12));
+    let (ranges, plan) = plan(&[("a", &a), ("b", &b), ("c", &c)], &COST_MODEL, &[2..3, 11..12]);
+
+    assert_eq!(ranges, [(0..2, vec!["a"]), (3..5, vec!["b"])]);
+    assert_eq!(plan.skipped, ["c"]);
+  }
+
+  #[test]
+  fn falls_back_to_separate_ranges_without_cost() {
+    let (a, b, c) = (TestBlock(0..4), TestBlock(2..6), TestBlock(7..8));
+    let cost_model = CostModel { request_overhead: Duration::MAX, ..COST_MODEL };
+    let (ranges, plan) = plan(&[("a", &a), ("b", &b), ("c", &c)], &cost_model, &[]);
+
+    assert_eq!(ranges, [(0..6, vec!["a", "b"]), (7..8, vec!["c"])]);
+    assert_eq!(plan.cost, None);
+  }
+
+  #[test]
+  fn vs1_plan_respects_its_max_block_len() {
+    let names = (0..30).map(|i| format!("{i}")).collect::<Vec<_>>();
+    let blocks = (0..30).map(|i| TestBlock((i * 10)..(i * 10 + 10))).collect::<Vec<_>>();
+    let commands = names.iter().map(String::as_str).zip(&blocks).collect::<Vec<_>>();
+
+    let cost_model = CostModel::new(Protocol::Vs1, &commands);
+    assert_eq!(cost_model.max_block_len, 119);
+
+    // Without a limit, all contiguous commands would be read at once.
+    let (ranges, plan) = plan(&commands, &cost_model, &[]);
+    assert!(ranges.iter().all(|(range, _)| range.len() <= cost_model.max_block_len), "{ranges:?}");
+    assert_eq!(ranges.iter().map(|(_, commands)| commands.len()).sum::<usize>(), commands.len());
+    assert!(plan.skipped.is_empty());
+
+    // A device command reading more bytes at once raises the limit, up to the maximum of the protocol.
+    let (large, huge) = (TestBlock(0..150), TestBlock(0..300));
+    assert_eq!(CostModel::new(Protocol::Vs1, &[("large", &large)]).max_block_len, 150);
+    assert_eq!(CostModel::new(Protocol::Vs1, &[("huge", &huge)]).max_block_len, 255);
+    assert_eq!(CostModel::new(Protocol::Vs2, &commands).max_block_len, 250);
+  }
+}
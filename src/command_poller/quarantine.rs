@@ -0,0 +1,178 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  fmt,
+  ops::Range,
+  time::Duration,
+};
+
+use rangemap::RangeSet;
+use tokio::time::Instant;
+use vcontrol::{Command, Value};
+
+/// Pseudo command whose value lists all quarantined commands. It is published once per poll cycle.
+pub const QUARANTINED_COMMANDS: &str = "quarantined_commands";
+
+/// Number of consecutive failed poll cycles after which a command is quarantined.
+const QUARANTINE_THRESHOLD: u32 = 5;
+
+/// Time after which a quarantined command or failing region is probed for the first time.
+const PROBE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Maximum time between probes, the interval is doubled after every failed probe.
+const MAX_PROBE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Maximum length of the list of quarantined commands, since longer states are rejected by Home Assistant.
+const MAX_STATE_LEN: usize = 255;
+
+/// Tracks commands which fail to be read or decoded.
+///
+/// Failures are only logged once per command until it succeeds again. Commands which keep failing are
+/// quarantined, i.e. no longer polled. Unused regions between commands which fail to be read are avoided when
+/// planning ranges.
+///
+/// Quarantined commands and failing regions are probed with increasing intervals, see [`Quarantine::due_probes`],
+/// and released once they can be read again.
+#[derive(Debug, Default)]
+pub struct Quarantine {
+  /// Number of consecutive failures per command.
+  failures: HashMap<&'static str, u32>,
+  quarantined: BTreeMap<&'static str, Probe>,
+  failing_regions: Vec<(Range<u16>, Probe)>,
+}
+
+/// Schedule for probing a quarantined command or failing region.
+#[derive(Debug)]
+struct Probe {
+  at: Instant,
+  interval: Duration,
+}
+
+impl Probe {
+  fn new() -> Self {
+    Self { at: Instant::now() + PROBE_INTERVAL, interval: PROBE_INTERVAL }
+  }
+
+  fn failed(&mut self) {
+    self.interval = (self.interval * 2).min(MAX_PROBE_INTERVAL);
+    self.at = Instant::now() + self.interval;
+  }
+}
+
+impl Quarantine {
+  /// Returns whether there are no quarantined commands or failing regions.
+  pub fn is_empty(&self) -> bool {
+    self.quarantined.is_empty() && self.failing_regions.is_empty()
+  }
+
+  pub fn contains(&self, command_name: &str) -> bool {
+    self.quarantined.contains_key(command_name)
+  }
+
+  /// Returns the regions which must not be read as part of a larger range.
+  pub fn failing_regions(&self) -> Vec<Range<u16>> {
+    self.failing_regions.iter().map(|(region, _)| region.clone()).collect()
+  }
+
+  /// Returns the quarantined commands and failing regions which are due to be probed.
+  pub fn due_probes(&self) -> (Vec<&'static str>, Vec<Range<u16>>) {
+    let now = Instant::now();
+    let command_names =
+      self.quarantined.iter().filter(|(_, probe)| probe.at <= now).map(|(&command_name, _)| command_name).collect();
+    let regions =
+      self.failing_regions.iter().filter(|(_, probe)| probe.at <= now).map(|(region, _)| region.clone()).collect();
+    (command_names, regions)
+  }
+
+  /// Records a success, returning whether the command was released from quarantine.
+  pub fn succeeded(&mut self, command_name: &'static str) -> bool {
+    if let Some(failures) = self.failures.remove(command_name) {
+      log::info!("Command {command_name} succeeded again after {failures} failures.");
+    }
+
+    let released = self.quarantined.remove(command_name).is_some();
+    if released {
+      log::info!("Releasing command {command_name} from quarantine.");
+    }
+    released
+  }
+
+  /// Records a failed probe of a quarantined command, postponing the next probe.
+  pub fn probe_failed(&mut self, command_name: &'static str, err: impl fmt::Display) {
+    if let Some(probe) = self.quarantined.get_mut(command_name) {
+      probe.failed();
+      log::debug!("Command {command_name} is still failing, probing again in {:?}: {err}", probe.interval);
+    }
+  }
+
+  /// Records a successful probe of a failing region, which is no longer avoided.
+  pub fn region_succeeded(&mut self, region: &Range<u16>) {
+    log::info!("Region {region:#06X?} succeeded again, no longer avoiding it.");
+    self.failing_regions.retain(|(failing_region, _)| failing_region != region);
+  }
+
+  /// Records a failed probe of a failing region, postponing the next probe.
+  pub fn region_failed(&mut self, region: &Range<u16>, err: impl fmt::Display) {
+    if let Some((_, probe)) = self.failing_regions.iter_mut().find(|(failing_region, _)| failing_region == region) {
+      probe.failed();
+      log::debug!("Region {region:#06X?} is still failing, probing again in {:?}: {err}", probe.interval);
+    }
+  }
+
+  /// Records a failure, returning whether the command was quarantined.
+  pub fn failed(&mut self, command_name: &'static str, command: &Command, err: impl fmt::Display) -> bool {
+    // Quarantined commands are only read when probed or refreshed.
+    if self.contains(command_name) {
+      self.probe_failed(command_name, err);
+      return false;
+    }
+
+    let failures = self.failures.entry(command_name).or_default();
+    *failures += 1;
+
+    if *failures == 1 {
+      log::warn!("Failed to read command {command_name}: {err}");
+    } else {
+      log::debug!("Failed to read command {command_name} ({failures} times): {err}");
+    }
+
+    if *failures < QUARANTINE_THRESHOLD {
+      return false;
+    }
+
+    log::error!(
+      "Quarantining command {command_name} at {:#06X} after {failures} consecutive failures: {err}",
+      command.addr()
+    );
+    self.failures.remove(command_name);
+    self.quarantined.insert(command_name, Probe::new());
+    true
+  }
+
+  /// Records the unused regions of a failing range whose commands can all be read on their own, returning
+  /// whether there are any.
+  pub fn failed_gaps(&mut self, range: Range<u16>, commands: &[(&'static str, &'static Command)]) -> bool {
+    let mut gaps = RangeSet::new();
+    gaps.insert(range);
+    for (_, command) in commands {
+      gaps.remove(command.addr()..(command.addr() + command.block_len() as u16));
+    }
+
+    for gap in gaps.iter() {
+      log::warn!("Avoiding unused region {gap:#06X?}, since it fails to be read.");
+      self.failing_regions.push((gap.clone(), Probe::new()));
+    }
+
+    !gaps.is_empty()
+  }
+
+  /// Returns the value of [`QUARANTINED_COMMANDS`].
+  pub fn value(&self) -> Value {
+    let mut state = self.quarantined.keys().copied().collect::<Vec<_>>().join(", ");
+    if state.len() > MAX_STATE_LEN {
+      let end = state.floor_char_boundary(MAX_STATE_LEN - '…'.len_utf8());
+      state.truncate(end);
+      state.push('…');
+    }
+    Value::String(state)
+  }
+}
@@ -0,0 +1,83 @@
+use std::{
+  collections::{BTreeSet, HashMap},
+  mem,
+  sync::Arc,
+};
+
+use tokio::sync::watch;
+use vcontrol::Command;
+
+/// Handle for requesting an immediate read of specific commands.
+#[derive(Clone)]
+pub struct Refresher {
+  readable_commands: Arc<HashMap<&'static str, &'static Command>>,
+  requested: Arc<watch::Sender<BTreeSet<&'static str>>>,
+}
+
+impl Refresher {
+  /// Requests reading the given commands ahead of the poll schedule. Their values are published to all consumers.
+  ///
+  /// Unknown and write-only commands are ignored, as are commands which are already pending.
+  pub fn refresh<'a>(&self, command_names: impl IntoIterator<Item = &'a str>) {
+    let command_names = command_names
+      .into_iter()
+      .filter_map(|command_name| match self.readable_commands.get_key_value(command_name) {
+        Some((&command_name, _)) => Some(command_name),
+        None => {
+          log::warn!("Cannot refresh command {command_name}, it is not readable.");
+          None
+        },
+      })
+      .collect::<Vec<_>>();
+
+    let mut added = 0;
+    self.requested.send_if_modified(|requested| {
+      added = command_names.iter().filter(|&&command_name| requested.insert(command_name)).count();
+      added > 0
+    });
+
+    if added > 0 {
+      log::info!("Refreshing {added} commands.");
+    }
+  }
+}
+
+/// Pending refresh requests, which are read by the poll loop.
+pub(super) struct Requests {
+  readable_commands: Arc<HashMap<&'static str, &'static Command>>,
+  requested: Arc<watch::Sender<BTreeSet<&'static str>>>,
+  rx: watch::Receiver<BTreeSet<&'static str>>,
+}
+
+impl Requests {
+  pub fn is_pending(&self) -> bool {
+    !self.requested.borrow().is_empty()
+  }
+
+  /// Waits until any command is pending.
+  pub async fn pending(&mut self) {
+    // The sender is never closed, since it is also owned by `self`.
+    let _ = self.rx.wait_for(|requested| !requested.is_empty()).await;
+  }
+
+  /// Takes all pending commands.
+  pub fn take(&mut self) -> Vec<(&'static str, &'static Command)> {
+    let mut requested = BTreeSet::new();
+    self.requested.send_if_modified(|pending| {
+      requested = mem::take(pending);
+      false
+    });
+
+    requested.into_iter().map(|command_name| (command_name, self.readable_commands[command_name])).collect()
+  }
+}
+
+/// Creates a refresher and the requests it passes to the poll loop.
+pub(super) fn refresher(readable_commands: HashMap<&'static str, &'static Command>) -> (Refresher, Requests) {
+  let readable_commands = Arc::new(readable_commands);
+  let (requested, rx) = watch::channel(BTreeSet::new());
+  let requested = Arc::new(requested);
+
+  let refresher = Refresher { readable_commands: readable_commands.clone(), requested: requested.clone() };
+  (refresher, Requests { readable_commands, requested, rx })
+}
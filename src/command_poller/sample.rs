@@ -0,0 +1,29 @@
+use std::ops::Range;
+
+use chrono::{DateTime, Utc};
+use vcontrol::Value;
+
+/// Pseudo command which is published at the end of every poll cycle, after all other samples of the cycle.
+///
+/// Its value is always [`Value::Empty`], only its `cycle` is meaningful.
+pub const CYCLE_END: &str = "cycle_end";
+
+/// A polled value.
+///
+/// Values with the same `cycle` and `range` were read with a single request, so they form a consistent snapshot.
+#[derive(Debug, Clone)]
+pub struct Sample {
+  pub command_name: &'static str,
+  pub value: Value,
+  /// Time when the value was read.
+  pub read_at: DateTime<Utc>,
+  /// Number of the poll cycle in which the value was read.
+  pub cycle: u64,
+  /// Address range read to get the value, or `None` if the value was not read from the controller. It is only used
+  /// for diagnostics.
+  pub range: Option<Range<u16>>,
+  /// Value of the command's previous sample, filled in when publishing.
+  ///
+  /// This allows consumers to detect changes, even if they did not receive the previous sample.
+  pub previous: Option<Value>,
+}
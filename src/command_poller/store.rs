@@ -0,0 +1,93 @@
+use std::{
+  collections::HashMap,
+  sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, Utc};
+use tokio::sync::watch;
+
+use crate::command_poller::Sample;
+
+/// Latest sample of each command, with the version in which it was published.
+type Samples = HashMap<&'static str, (u64, Sample)>;
+
+/// Creates a store for the latest sample of each command.
+pub(super) fn store() -> (Publisher, Store) {
+  let samples = Arc::new(Mutex::new(Samples::new()));
+  let (version_tx, version_rx) = watch::channel(0);
+  let created_at = Utc::now();
+  (
+    Publisher { samples: samples.clone(), version: Arc::new(version_tx) },
+    Store { samples, version: version_rx, created_at },
+  )
+}
+
+/// Publishes samples into the store. The store is closed once all publishers are dropped.
+#[derive(Clone)]
+pub(super) struct Publisher {
+  samples: Arc<Mutex<Samples>>,
+  version: Arc<watch::Sender<u64>>,
+}
+
+impl Publisher {
+  /// Replaces the latest sample of the command and notifies all consumers.
+  pub fn publish(&self, mut sample: Sample) {
+    let mut samples = self.samples.lock().unwrap();
+    sample.previous = samples.get(sample.command_name).map(|(_, previous)| previous.value.clone());
+    self.version.send_modify(|version| {
+      *version += 1;
+      samples.insert(sample.command_name, (*version, sample));
+    });
+  }
+}
+
+/// Handle for reading the latest sample of each command.
+#[derive(Clone)]
+pub struct Store {
+  samples: Arc<Mutex<Samples>>,
+  version: watch::Receiver<u64>,
+  created_at: DateTime<Utc>,
+}
+
+impl Store {
+  /// Returns a receiver for all samples, starting with the latest sample of each command already in the store.
+  pub fn updates(&self) -> Updates {
+    Updates { samples: self.samples.clone(), version: self.version.clone(), seen: 0, created_at: self.created_at }
+  }
+}
+
+/// Error returned when the store is closed.
+#[derive(Debug)]
+pub struct Closed;
+
+/// Receiver for updated samples.
+///
+/// Unlike a broadcast channel, a receiver which falls behind never misses the current value of a command, it
+/// only skips intermediate values.
+pub struct Updates {
+  samples: Arc<Mutex<Samples>>,
+  version: watch::Receiver<u64>,
+  seen: u64,
+  created_at: DateTime<Utc>,
+}
+
+impl Updates {
+  /// Waits for samples published since the last call and returns them in the order they were published.
+  pub async fn changed(&mut self) -> Result<Vec<Sample>, Closed> {
+    let seen = self.seen;
+    self.version.wait_for(|&version| version > seen).await.map_err(|_| Closed)?;
+
+    let samples = self.samples.lock().unwrap();
+    let mut updated = samples.values().filter(|(version, _)| *version > seen).collect::<Vec<_>>();
+    updated.sort_by_key(|(version, _)| *version);
+
+    self.seen = updated.last().map_or(seen, |(version, _)| *version);
+    Ok(updated.into_iter().map(|(_, sample)| sample.clone()).collect())
+  }
+
+  /// Returns when the latest sample of the command was read, or when the store was created if it was never read.
+  pub fn read_at(&self, command_name: &str) -> DateTime<Utc> {
+    let samples = self.samples.lock().unwrap();
+    samples.get(command_name).map_or(self.created_at, |(_, sample)| sample.read_at)
+  }
+}
@@ -0,0 +1,90 @@
+use std::{
+  collections::{BTreeMap, HashSet},
+  sync::Arc,
+};
+
+use tokio::sync::watch;
+
+/// Currently subscribed commands.
+#[derive(Debug, Default)]
+pub(super) struct Subscribed {
+  /// Number of subscriptions for each command.
+  pub commands: BTreeMap<&'static str, usize>,
+  /// Number of subscriptions by listeners which want values as soon as possible.
+  pub listeners: usize,
+}
+
+/// Registry of the commands which are currently consumed and therefore need to be polled.
+#[derive(Clone)]
+pub struct Subscriptions {
+  subscribed: Arc<watch::Sender<Subscribed>>,
+}
+
+impl Subscriptions {
+  pub fn new() -> Self {
+    Self { subscribed: Arc::new(watch::Sender::new(Subscribed::default())) }
+  }
+
+  /// Subscribes a listener, e.g. a connected client, to the given commands until the returned subscription is
+  /// dropped. Commands are polled at full speed while any listener is subscribed.
+  pub fn subscribe(&self, command_names: impl IntoIterator<Item = &'static str>) -> Subscription {
+    self.add(command_names, true)
+  }
+
+  /// Subscribes a background consumer to the given commands until the returned subscription is dropped.
+  /// Commands are only polled slowly unless a listener is subscribed as well.
+  pub fn subscribe_background(&self, command_names: impl IntoIterator<Item = &'static str>) -> Subscription {
+    self.add(command_names, false)
+  }
+
+  fn add(&self, command_names: impl IntoIterator<Item = &'static str>, listener: bool) -> Subscription {
+    let command_names = command_names.into_iter().collect::<HashSet<_>>();
+
+    self.subscribed.send_if_modified(|subscribed| {
+      let mut modified = listener && subscribed.listeners == 0;
+      subscribed.listeners += usize::from(listener);
+
+      for &command_name in &command_names {
+        let count = subscribed.commands.entry(command_name).or_default();
+        modified |= *count == 0;
+        *count += 1;
+      }
+      modified
+    });
+
+    Subscription { subscriptions: self.clone(), command_names, listener }
+  }
+
+  /// Returns a receiver which is notified whenever the set of subscribed commands or whether any listener is
+  /// subscribed changes.
+  pub(super) fn watch(&self) -> watch::Receiver<Subscribed> {
+    self.subscribed.subscribe()
+  }
+}
+
+/// Keeps commands subscribed, see [`Subscriptions::subscribe`].
+pub struct Subscription {
+  subscriptions: Subscriptions,
+  command_names: HashSet<&'static str>,
+  listener: bool,
+}
+
+impl Drop for Subscription {
+  fn drop(&mut self) {
+    self.subscriptions.subscribed.send_if_modified(|subscribed| {
+      subscribed.listeners -= usize::from(self.listener);
+      let mut modified = self.listener && subscribed.listeners == 0;
+
+      for command_name in &self.command_names {
+        if let Some(count) = subscribed.commands.get_mut(command_name) {
+          *count -= 1;
+          if *count == 0 {
+            subscribed.commands.remove(command_name);
+            modified = true;
+          }
+        }
+      }
+      modified
+    });
+  }
+}
@@ -0,0 +1,280 @@
+use std::{
+  collections::BTreeMap,
+  env, fmt, fs, io,
+  path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use vcontrol::{Device, Value};
+
+use crate::command_poller::{Subscriptions, Updates};
+use crate::time_zone::TimeZone;
+
+/// Command returning the controller's ring buffer of the most recent errors.
+const ERROR_HISTORY_COMMAND: &str = "ecnsysEventType~Error";
+
+const DEFAULT_PATH: &str = "/var/lib/heating/error_history.json";
+
+/// A distinct error event, identified by its code and the time it was raised.
+#[derive(Debug, Clone, PartialEq)]
+struct ErrorEntry {
+  code: u8,
+  text: String,
+  /// Unix timestamp of when the error was raised, according to the controller.
+  time: i64,
+  /// Unix timestamp of when the error was first observed in the controller's ring buffer.
+  first_seen: i64,
+  /// Unix timestamp of when the error was last observed in the controller's ring buffer.
+  last_seen: i64,
+}
+
+impl ErrorEntry {
+  fn to_json(&self) -> serde_json::Value {
+    json!({
+      "code": self.code,
+      "text": self.text,
+      "time": self.time,
+      "first_seen": self.first_seen,
+      "last_seen": self.last_seen,
+    })
+  }
+
+  fn from_json(value: &serde_json::Value) -> Option<Self> {
+    Some(Self {
+      code: u8::try_from(value.get("code")?.as_u64()?).ok()?,
+      text: value.get("text")?.as_str()?.to_owned(),
+      time: value.get("time")?.as_i64()?,
+      first_seen: value.get("first_seen")?.as_i64()?,
+      last_seen: value.get("last_seen")?.as_i64()?,
+    })
+  }
+}
+
+/// Persistent history of all errors observed in the controller's ring buffer.
+///
+/// The controller only keeps the last few errors, so entries are kept here after they are pushed out of the ring
+/// buffer. Entries are ordered by the time they were raised.
+#[derive(Debug)]
+pub struct ErrorHistory {
+  path: PathBuf,
+  entries: Vec<ErrorEntry>,
+}
+
+impl ErrorHistory {
+  /// Returns the path of the history file from the `ERROR_HISTORY_PATH` environment variable.
+  pub fn path_from_env() -> PathBuf {
+    env::var_os("ERROR_HISTORY_PATH").map(PathBuf::from).unwrap_or_else(|| DEFAULT_PATH.into())
+  }
+
+  /// Loads the history from the given file, or returns an empty history if the file does not exist.
+  pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+    let path = path.as_ref().to_owned();
+
+    let contents = match fs::read_to_string(&path) {
+      Ok(contents) => contents,
+      Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self { path, entries: vec![] }),
+      Err(err) => return Err(err),
+    };
+
+    let value: serde_json::Value = serde_json::from_str(&contents)?;
+    let entries = value
+      .as_array()
+      .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "error history is not an array"))?
+      .iter()
+      .map(|entry| {
+        ErrorEntry::from_json(entry)
+          .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("invalid error history entry: {entry}")))
+      })
+      .collect::<Result<_, _>>()?;
+
+    Ok(Self { path, entries })
+  }
+
+  /// Writes the history to its file, replacing it atomically.
+  pub fn save(&self) -> io::Result<()> {
+    if let Some(parent) = self.path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    let value = serde_json::Value::Array(self.entries.iter().map(ErrorEntry::to_json).collect());
+    let tmp_path = self.path.with_extension("json.tmp");
+    fs::write(&tmp_path, serde_json::to_string_pretty(&value)?)?;
+    fs::rename(tmp_path, &self.path)
+  }
+
+  /// Returns the number of distinct events for each error code.
+  pub fn counts(&self) -> BTreeMap<u8, usize> {
+    let mut counts = BTreeMap::new();
+    for entry in &self.entries {
+      *counts.entry(entry.code).or_default() += 1;
+    }
+    counts
+  }
+
+  /// Records the errors in the controller's ring buffer read at `read_at`, returning whether a new error was added.
+  pub fn record(&mut self, device: &Device, time_zone: TimeZone, value: &Value, read_at: DateTime<Utc>) -> bool {
+    let Value::Array(errors) = value else {
+      log::warn!("Unsupported value for {ERROR_HISTORY_COMMAND}: {value:?}");
+      return false;
+    };
+
+    let now = read_at.timestamp();
+    let mut added = false;
+
+    for error in errors {
+      let Value::Error(error) = error else {
+        log::warn!("Unsupported value for {ERROR_HISTORY_COMMAND}: {error:?}");
+        continue;
+      };
+
+      // Empty slots in the ring buffer have no time.
+      let Some(time) = error.time().and_then(|time| time_zone.unix_timestamp(time)) else { continue };
+      let code = error.index();
+
+      if let Some(entry) = self.entries.iter_mut().find(|entry| entry.code == code && entry.time == time) {
+        entry.last_seen = now;
+        continue;
+      }
+
+      let text = error.to_str(device).unwrap_or_default().to_owned();
+      log::info!("Recording error {code:02X} ({text}) raised at {time}.");
+      self.entries.push(ErrorEntry { code, text, time, first_seen: now, last_seen: now });
+      added = true;
+    }
+
+    if added {
+      self.entries.sort_by_key(|entry| (entry.time, entry.code));
+    }
+
+    added
+  }
+}
+
+impl fmt::Display for ErrorHistory {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let format_time = |timestamp| {
+      DateTime::from_timestamp(timestamp, 0)
+        .map(|time| time.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+    };
+
+    for entry in &self.entries {
+      writeln!(
+        f,
+        "{}  {:02X}  {} (first seen {}, last seen {})",
+        format_time(entry.time),
+        entry.code,
+        entry.text,
+        format_time(entry.first_seen),
+        format_time(entry.last_seen),
+      )?;
+    }
+
+    if !self.entries.is_empty() {
+      writeln!(f)?;
+    }
+
+    for (code, count) in self.counts() {
+      let text =
+        self.entries.iter().find(|entry| entry.code == code).map(|entry| entry.text.as_str()).unwrap_or_default();
+      writeln!(f, "{code:02X}  {count:>4}×  {text}")?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Records every value of the controller's error ring buffer in the history, saving it whenever a new error is added.
+///
+/// Only the last-seen times of known errors are updated in memory; they are written along with the next new error.
+pub async fn error_history_loop(
+  mut updates: Updates,
+  subscriptions: Subscriptions,
+  mut history: ErrorHistory,
+  device: &'static Device,
+  time_zone: TimeZone,
+) {
+  let _subscription = subscriptions.subscribe_background([ERROR_HISTORY_COMMAND]);
+
+  while let Ok(samples) = updates.changed().await {
+    for sample in samples.iter().filter(|sample| sample.command_name == ERROR_HISTORY_COMMAND) {
+      if history.record(device, time_zone, &sample.value, sample.read_at)
+        && let Err(err) = history.save()
+      {
+        log::error!("Failed to save error history to {}: {err}", history.path.display());
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::TimeZone as _;
+  use vcontrol::{device::ECOTRONIC, types};
+
+  use super::*;
+
+  const TIME_ZONE: TimeZone = TimeZone::Iana(chrono_tz::UTC);
+
+  fn error(code: u8, hour: u8) -> Value {
+    Value::Error(types::Error::new(code, types::DateTime::new(2025, 1, 15, hour, 0, 0).unwrap()))
+  }
+
+  fn empty_slot() -> Value {
+    Value::Error(types::Error::from_bytes(&[0; 9]).unwrap())
+  }
+
+  fn read_at(minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 1, 15, 12, minute, 0).unwrap()
+  }
+
+  #[test]
+  fn deduplicates_errors_by_code_and_time() {
+    let mut history = ErrorHistory { path: PathBuf::new(), entries: vec![] };
+    let errors = Value::Array(vec![error(0x10, 8), error(0x10, 9)]);
+
+    assert!(history.record(&ECOTRONIC, TIME_ZONE, &errors, read_at(0)));
+    assert!(!history.record(&ECOTRONIC, TIME_ZONE, &errors, read_at(5)));
+
+    assert_eq!(history.entries.len(), 2);
+    for entry in &history.entries {
+      assert_eq!(entry.first_seen, read_at(0).timestamp());
+      assert_eq!(entry.last_seen, read_at(5).timestamp());
+    }
+    assert_eq!(history.counts(), BTreeMap::from([(0x10, 2)]));
+  }
+
+  #[test]
+  fn skips_empty_ring_buffer_slots() {
+    let mut history = ErrorHistory { path: PathBuf::new(), entries: vec![] };
+
+    let errors = Value::Array(vec![empty_slot(), empty_slot()]);
+    assert!(!history.record(&ECOTRONIC, TIME_ZONE, &errors, read_at(0)));
+    assert!(history.entries.is_empty());
+
+    let errors = Value::Array(vec![error(0x20, 8), empty_slot()]);
+    assert!(history.record(&ECOTRONIC, TIME_ZONE, &errors, read_at(0)));
+    assert_eq!(history.counts(), BTreeMap::from([(0x20, 1)]));
+  }
+
+  #[test]
+  fn saves_and_loads_json() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("history").join("error_history.json");
+
+    // A missing file is an empty history.
+    let mut history = ErrorHistory::load(&path).unwrap();
+    assert!(history.entries.is_empty());
+
+    let errors = Value::Array(vec![error(0x20, 9), error(0x10, 8), empty_slot()]);
+    history.record(&ECOTRONIC, TIME_ZONE, &errors, read_at(0));
+    history.record(&ECOTRONIC, TIME_ZONE, &errors, read_at(5));
+    history.save().unwrap();
+
+    let loaded = ErrorHistory::load(&path).unwrap();
+    assert_eq!(loaded.path, path);
+    assert_eq!(loaded.entries, history.entries);
+    assert_eq!(loaded.entries.iter().map(|entry| entry.code).collect::<Vec<_>>(), [0x10, 0x20]);
+  }
+}
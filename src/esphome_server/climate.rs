@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+
+use esphome_native_api::proto::version_2026_1_5::{
+  ClimateAction, ClimateCommandRequest, ClimateMode, ClimatePreset, ClimateStateResponse, EntityCategory,
+  ListEntitiesClimateResponse,
+};
+use vcontrol::{Command, DataType, VControl, Value};
+
+use crate::esphome_server::sub_devices::SubDevice;
+use crate::party_mode::{self, PARTY_TIMER, PartyModeError};
+
+const SUPPORTS_CURRENT_TEMPERATURE: u32 = 1 << 0;
+const SUPPORTS_ACTION: u32 = 1 << 5;
+
+const PARTY_PRESET: &str = "Party";
+
+/// Commands making up the climate entity of a heating circuit.
+///
+/// Apart from the operating mode and the comfort temperature, commands not supported by the device are ignored.
+struct Circuit {
+  name: &'static str,
+  /// Number of the heating circuit used by the party mode.
+  number: i32,
+  sub_device: SubDevice,
+  /// Standby (0) or standard (1) mode.
+  operating_mode: &'static str,
+  comfort_temperature: &'static str,
+  eco_temperature: &'static str,
+  party_temperature: &'static str,
+  room_temperature: &'static str,
+  saver_mode: &'static str,
+  /// Party duration configured for this circuit, which is started by the shared party timer.
+  party_duration: &'static str,
+  vacation_mode: &'static str,
+  pump: &'static str,
+}
+
+const CIRCUITS: &[Circuit] = &[
+  Circuit {
+    name: "HC1",
+    number: 1,
+    sub_device: SubDevice::HeatingCircuit1,
+    operating_mode: "Ecotronic_Betriebsart_HK1",
+    comfort_temperature: "Ecotronic_Raumsoll_Normal_HK1",
+    eco_temperature: "Ecotronic_Raumsoll_Reduziert_HK1",
+    party_temperature: "Ecotronic_BedienPartybetriebM1",
+    room_temperature: "Ecotronic_Heizung_Raumtemperatur_HK1",
+    saver_mode: "Ecotronic_BedienSparbetrieb_HK1",
+    party_duration: "KF2_KonfiPartyzeitA1M1",
+    vacation_mode: "Ecotronic_HK_Ferienbetrieb_HK1",
+    pump: "Ecotronic_Pumpe_HK1",
+  },
+  Circuit {
+    name: "HC2",
+    number: 2,
+    sub_device: SubDevice::HeatingCircuit2,
+    operating_mode: "Ecotronic_Betriebsart_HK2",
+    comfort_temperature: "Ecotronic_Raumsoll_Normal_HK2",
+    eco_temperature: "Ecotronic_Raumsoll_Reduziert_HK2",
+    party_temperature: "Ecotronic_BedienPartybetriebM2",
+    room_temperature: "Ecotronic_Heizung_Raumtemperatur_HK2",
+    saver_mode: "Ecotronic_BedienSparbetrieb_HK2",
+    party_duration: "KF2_KonfiPartyzeitM2",
+    vacation_mode: "Ecotronic_HK_Ferienbetrieb_HK2",
+    pump: "Ecotronic_Pumpe_HK2",
+  },
+];
+
+/// A climate entity controlling a heating circuit.
+///
+/// The standard operating mode follows the circuit's schedule, so it is mapped to [`ClimateMode::Auto`].
+/// The target temperature is the room setpoint of the active preset.
+pub struct Climate {
+  pub key: u32,
+  circuit: &'static Circuit,
+  commands: HashMap<&'static str, &'static Command>,
+}
+
+impl Climate {
+  fn is_readable(&self, command_name: &str) -> bool {
+    self.commands.get(command_name).is_some_and(|command| command.access_mode().is_read())
+  }
+
+  fn is_writable(&self, command_name: &str) -> bool {
+    self.commands.get(command_name).is_some_and(|command| command.access_mode().is_write())
+  }
+
+  fn supports_party_mode(&self) -> bool {
+    self.is_writable(PARTY_TIMER) && self.is_writable(self.circuit.party_duration)
+  }
+
+  /// Returns whether values for all readable commands of this climate entity are known.
+  pub fn is_complete(&self, values: &HashMap<&str, Value>) -> bool {
+    self
+      .commands
+      .iter()
+      .all(|(command_name, command)| !command.access_mode().is_read() || values.contains_key(command_name))
+  }
+
+  /// Returns whether the given command is part of this climate entity.
+  pub fn contains(&self, command_name: &str) -> bool {
+    self.commands.contains_key(command_name)
+  }
+
+  /// Returns the sub-device of the heating circuit.
+  pub fn sub_device(&self) -> SubDevice {
+    self.circuit.sub_device
+  }
+
+  /// Returns all commands of this climate entity.
+  pub fn command_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+    self.commands.keys().copied()
+  }
+
+  /// Returns all readable commands of this climate entity.
+  pub fn readable_command_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+    self.commands.iter().filter(|(_, command)| command.access_mode().is_read()).map(|(&command_name, _)| command_name)
+  }
+
+  pub fn entity(&self) -> ListEntitiesClimateResponse {
+    let circuit = self.circuit;
+    let comfort_temperature = self.commands[circuit.comfort_temperature];
+
+    let supports_current_temperature = self.is_readable(circuit.room_temperature);
+    let supports_action = self.is_readable(circuit.pump);
+
+    let mut supported_presets = vec![ClimatePreset::Comfort as i32];
+    if self.is_writable(circuit.saver_mode) {
+      supported_presets.push(ClimatePreset::Eco as i32);
+    }
+
+    let mut supported_custom_presets = vec![];
+    if self.supports_party_mode() {
+      supported_custom_presets.push(PARTY_PRESET.to_owned());
+    }
+
+    let step = if comfort_temperature.data_type() == DataType::Int { 1.0 } else { 0.5 };
+
+    ListEntitiesClimateResponse {
+      device_id: self.circuit.sub_device.id(),
+      object_id: format!("{}_heating", circuit.name.to_lowercase()),
+      key: self.key,
+      name: format!("{} Heating", circuit.name),
+      icon: "".into(),
+      disabled_by_default: false,
+      entity_category: EntityCategory::None as i32,
+      supports_current_temperature,
+      supports_two_point_target_temperature: false,
+      supported_modes: vec![ClimateMode::Off as i32, ClimateMode::Auto as i32],
+      visual_min_temperature: comfort_temperature.lower_bound().unwrap_or(10.0) as f32,
+      visual_max_temperature: comfort_temperature.upper_bound().unwrap_or(30.0) as f32,
+      visual_target_temperature_step: step,
+      visual_current_temperature_step: 0.1,
+      #[allow(deprecated)]
+      legacy_supports_away: false,
+      supports_action,
+      supported_fan_modes: vec![],
+      supported_swing_modes: vec![],
+      supported_custom_fan_modes: vec![],
+      supported_presets,
+      supported_custom_presets,
+      supports_current_humidity: false,
+      supports_target_humidity: false,
+      visual_min_humidity: 0.0,
+      visual_max_humidity: 0.0,
+      feature_flags: if supports_current_temperature { SUPPORTS_CURRENT_TEMPERATURE } else { 0 }
+        | if supports_action { SUPPORTS_ACTION } else { 0 },
+    }
+  }
+
+  /// Returns the preset and custom preset for the given values.
+  fn preset(&self, values: &HashMap<&str, Value>) -> (ClimatePreset, &'static str) {
+    let circuit = self.circuit;
+    let is_on = |command_name| matches!(values.get(command_name), Some(Value::Int(1)));
+
+    if is_on(circuit.vacation_mode) {
+      (ClimatePreset::Away, "")
+    } else if values.get(PARTY_TIMER).is_some_and(party_mode::is_active) {
+      (ClimatePreset::None, PARTY_PRESET)
+    } else if is_on(circuit.saver_mode) {
+      (ClimatePreset::Eco, "")
+    } else {
+      (ClimatePreset::Comfort, "")
+    }
+  }
+
+  /// Returns the setpoint command used for the given preset.
+  fn target_temperature_command(&self, preset: (ClimatePreset, &str)) -> &'static str {
+    match preset {
+      (ClimatePreset::Eco, _) => self.circuit.eco_temperature,
+      (_, PARTY_PRESET) if self.is_readable(self.circuit.party_temperature) => self.circuit.party_temperature,
+      _ => self.circuit.comfort_temperature,
+    }
+  }
+
+  pub fn state(&self, values: &HashMap<&str, Value>) -> ClimateStateResponse {
+    let circuit = self.circuit;
+
+    let temperature = |command_name| match values.get(command_name) {
+      Some(Value::Int(n)) => *n as f32,
+      Some(Value::Double(n)) => *n as f32,
+      _ => f32::NAN,
+    };
+
+    let mode = match values.get(circuit.operating_mode) {
+      Some(Value::Int(0)) => ClimateMode::Off,
+      _ => ClimateMode::Auto,
+    };
+
+    let preset = self.preset(values);
+
+    let action = match (mode, values.get(circuit.pump)) {
+      (ClimateMode::Off, _) => ClimateAction::Off,
+      (_, Some(Value::Int(1))) => ClimateAction::Heating,
+      _ => ClimateAction::Idle,
+    };
+
+    ClimateStateResponse {
+      device_id: self.circuit.sub_device.id(),
+      key: self.key,
+      mode: mode as i32,
+      current_temperature: temperature(circuit.room_temperature),
+      target_temperature: temperature(self.target_temperature_command(preset)),
+      target_temperature_low: 0.0,
+      target_temperature_high: 0.0,
+      #[allow(deprecated)]
+      unused_legacy_away: false,
+      action: action as i32,
+      fan_mode: 0,
+      swing_mode: 0,
+      custom_fan_mode: "".into(),
+      preset: preset.0 as i32,
+      custom_preset: preset.1.to_owned(),
+      current_humidity: 0.0,
+      target_humidity: 0.0,
+    }
+  }
+
+  /// Returns the state with unknown temperatures, since climate entities cannot report a missing state.
+  pub fn missing_state(&self, values: &HashMap<&str, Value>) -> ClimateStateResponse {
+    ClimateStateResponse { current_temperature: f32::NAN, target_temperature: f32::NAN, ..self.state(values) }
+  }
+
+  /// Reads the current values of all readable commands of this climate entity.
+  pub async fn read_values(&self, vcontrol: &mut VControl) -> HashMap<&'static str, Value> {
+    let command_names = self.commands.keys().copied().collect::<Vec<_>>();
+    self.read(vcontrol, &command_names).await
+  }
+
+  /// Reads the current values of the given commands, skipping those which are not readable.
+  async fn read(&self, vcontrol: &mut VControl, command_names: &[&'static str]) -> HashMap<&'static str, Value> {
+    let mut values = HashMap::new();
+
+    for &command_name in command_names {
+      if !self.is_readable(command_name) {
+        continue;
+      }
+
+      match vcontrol.get(command_name).await {
+        Ok(output) => {
+          values.insert(command_name, output.value);
+        },
+        Err(err) => log::error!("Failed to get value for {command_name}: {err}"),
+      }
+    }
+
+    values
+  }
+
+  /// Reads the values determining the active preset.
+  async fn read_preset(&self, vcontrol: &mut VControl) -> (ClimatePreset, &'static str) {
+    let circuit = self.circuit;
+    let values = self.read(vcontrol, &[circuit.vacation_mode, PARTY_TIMER, circuit.saver_mode]).await;
+    self.preset(&values)
+  }
+
+  /// Applies the changes requested by a climate command.
+  ///
+  /// The controller has a single party timer, so starting or stopping party mode affects both heating circuits.
+  /// Vacation mode is only shown as the [`ClimatePreset::Away`] preset, since it needs a begin and end date and
+  /// therefore must be set using the vacation services.
+  pub async fn apply(&self, vcontrol: &mut VControl, request: &ClimateCommandRequest) -> Result<(), PartyModeError> {
+    let circuit = self.circuit;
+
+    if request.has_mode {
+      let mode = match ClimateMode::try_from(request.mode) {
+        Ok(ClimateMode::Off) => 0,
+        Ok(ClimateMode::Auto) => 1,
+        mode => {
+          log::warn!("Unsupported mode for {}: {mode:?}", circuit.name);
+          return Ok(());
+        },
+      };
+
+      set(vcontrol, circuit.operating_mode, Value::Int(mode)).await?;
+    }
+
+    if request.has_custom_preset && request.custom_preset == PARTY_PRESET {
+      if self.supports_party_mode() {
+        log::info!("Starting party mode for {}.", circuit.name);
+        party_mode::start(vcontrol, circuit.number).await?;
+      }
+    } else if request.has_preset {
+      match ClimatePreset::try_from(request.preset) {
+        Ok(preset @ (ClimatePreset::Comfort | ClimatePreset::Eco)) => {
+          if self.supports_party_mode() && self.read_preset(vcontrol).await.1 == PARTY_PRESET {
+            log::info!("Stopping party mode for {}.", circuit.name);
+            party_mode::set_party_mode(vcontrol, 0, 0).await?;
+          }
+
+          if self.is_writable(circuit.saver_mode) {
+            set(vcontrol, circuit.saver_mode, Value::Int((preset == ClimatePreset::Eco).into())).await?;
+          }
+        },
+        preset => log::warn!("Unsupported preset for {}: {preset:?}", circuit.name),
+      }
+    }
+
+    if request.has_target_temperature {
+      let preset = self.read_preset(vcontrol).await;
+      let command_name = self.target_temperature_command(preset);
+
+      let target_temperature = f64::from(request.target_temperature);
+      let value = if self.commands[command_name].data_type() == DataType::Int {
+        Value::Int(target_temperature.round() as i64)
+      } else {
+        Value::Double(target_temperature)
+      };
+
+      set(vcontrol, command_name, value).await?;
+    }
+
+    Ok(())
+  }
+}
+
+async fn set(vcontrol: &mut VControl, command_name: &str, value: Value) -> Result<(), vcontrol::Error> {
+  log::info!("Setting value for {command_name}: {value:?}");
+  vcontrol.set(command_name, value).await
+}
+
+/// Returns a climate entity for each heating circuit supported by the device, allocating keys from `next_key`.
+pub fn climates(commands: &HashMap<&'static str, &'static Command>, next_key: &mut u32) -> Vec<Climate> {
+  let mut climates = Vec::new();
+
+  for circuit in CIRCUITS {
+    let is_writable = |command_name| commands.get(command_name).is_some_and(|command| command.access_mode().is_write());
+    if !is_writable(circuit.operating_mode) || !is_writable(circuit.comfort_temperature) {
+      continue;
+    }
+
+    let circuit_commands = [
+      circuit.operating_mode,
+      circuit.comfort_temperature,
+      circuit.eco_temperature,
+      circuit.party_temperature,
+      circuit.room_temperature,
+      circuit.saver_mode,
+      circuit.party_duration,
+      PARTY_TIMER,
+      circuit.vacation_mode,
+      circuit.pump,
+    ]
+    .into_iter()
+    .filter_map(|command_name| commands.get(command_name).map(|&command| (command_name, command)))
+    .collect();
+
+    climates.push(Climate { key: *next_key, circuit, commands: circuit_commands });
+    *next_key += 1;
+  }
+
+  climates
+}
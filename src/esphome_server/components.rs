@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use vcontrol::{Command, VControl, Value};
+
+use crate::esphome_server::profiles::Profile;
+use crate::esphome_server::sub_devices::SubDevice;
+
+const MAX_PROBES: u8 = 8;
+
+/// Optional hardware which is not present on every installation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Component {
+  HeatingCircuit1,
+  HeatingCircuit2,
+  HotWater,
+  Buffer,
+  ChangeoverUnit,
+  /// A suction probe of the changeover unit, numbered from 1.
+  Probe(u8),
+}
+
+impl Component {
+  /// Returns the optional component the entities of the given sub-device belong to.
+  fn for_sub_device(sub_device: SubDevice) -> Option<Self> {
+    match sub_device {
+      SubDevice::HeatingCircuit1 => Some(Self::HeatingCircuit1),
+      SubDevice::HeatingCircuit2 => Some(Self::HeatingCircuit2),
+      SubDevice::HotWater => Some(Self::HotWater),
+      SubDevice::Buffer => Some(Self::Buffer),
+      SubDevice::ChangeoverUnit => Some(Self::ChangeoverUnit),
+      SubDevice::Main | SubDevice::Boiler | SubDevice::PelletStore => None,
+    }
+  }
+}
+
+/// Configuration commands used to detect the installed components of a device family.
+pub struct Detection {
+  /// Commands which are non-zero if the corresponding component is installed.
+  pub flags: &'static [(&'static str, Component)],
+  /// Commands holding the system scheme (coding address 00), of which the first supported one is used.
+  pub system_scheme: &'static [&'static str],
+  /// Command holding the number of suction probes.
+  pub probe_count: Option<&'static str>,
+  /// Commands which are only meaningful if the corresponding component is installed, in addition to the entities
+  /// of the component's sub-device, e.g. commands only used by climates.
+  pub commands: &'static [(&'static str, Component)],
+}
+
+impl Detection {
+  /// Detects nothing, so that all components are assumed to be installed.
+  pub const NONE: Self = Self { flags: &[], system_scheme: &[], probe_count: None, commands: &[] };
+}
+
+/// Returns the components included in the given system scheme.
+///
+/// The schemes are listed in the mapping of the system scheme commands, e.g. 6 is `A1 + M2 + DHW`. A1 is the heating
+/// circuit without mixer (circuit 1) and M2 the first mixer circuit (circuit 2). Devices with a single heating circuit
+/// only use 1 (`Boiler without DHW`) and 2 (`Boiler with DHW`).
+fn system_scheme_components(scheme: i64) -> Option<[(Component, bool); 3]> {
+  if !(0..=10).contains(&scheme) {
+    return None;
+  }
+
+  Some([
+    (Component::HeatingCircuit1, matches!(scheme, 1 | 2 | 5 | 6 | 9 | 10)),
+    (Component::HeatingCircuit2, scheme >= 3),
+    (Component::HotWater, scheme > 0 && scheme % 2 == 0),
+  ])
+}
+
+/// Components detected from the controller's configuration.
+///
+/// Components which cannot be detected, e.g. because the device does not support the corresponding
+/// configuration command, are assumed to be installed.
+#[derive(Debug, Clone)]
+pub struct Components {
+  detected: Vec<(Component, bool)>,
+  probes: Option<u8>,
+}
+
+impl Components {
+  pub async fn detect(vcontrol: &mut VControl, detection: &Detection) -> Self {
+    let mut get = async |command_name: &str| {
+      if !vcontrol.device().commands().contains_key(command_name) {
+        return None;
+      }
+
+      match vcontrol.get(command_name).await {
+        Ok(output) => match output.value {
+          Value::Int(n) => Some(n),
+          value => {
+            log::warn!("Unsupported value for {command_name}: {value:?}");
+            None
+          },
+        },
+        Err(err) => {
+          log::error!("Failed to get value for {command_name}: {err}");
+          None
+        },
+      }
+    };
+
+    let mut detected = vec![];
+
+    for &command_name in detection.system_scheme {
+      let Some(scheme) = get(command_name).await else { continue };
+
+      match system_scheme_components(scheme) {
+        Some(components) => detected.extend(components),
+        None => log::warn!("Unknown system scheme {scheme} for {command_name}."),
+      }
+      break;
+    }
+
+    for &(command_name, component) in detection.flags {
+      if let Some(n) = get(command_name).await {
+        detected.push((component, n != 0));
+      }
+    }
+
+    let probes = match detection.probe_count {
+      Some(command_name) => get(command_name).await.map(|n| n.clamp(0, MAX_PROBES.into()) as u8),
+      None => None,
+    };
+
+    let components = Self { detected, probes };
+    log::info!("Detected components: {components:?}");
+    components
+  }
+
+  pub fn is_installed(&self, component: Component) -> bool {
+    match component {
+      // A single probe is connected directly, without a changeover unit.
+      Component::ChangeoverUnit => self.probes.is_none_or(|probes| probes > 1),
+      Component::Probe(n) => self.probes.is_none_or(|probes| probes > 1 && n <= probes),
+      component => self.detected.iter().all(|&(detected, installed)| detected != component || installed),
+    }
+  }
+
+  /// Removes all commands belonging to components which are not installed, so that no entities,
+  /// climates or services are created for them.
+  pub fn retain_installed(&self, commands: &mut HashMap<&'static str, &'static Command>, profile: &Profile) {
+    let entity_components = profile
+      .entities
+      .iter()
+      .filter_map(|&(command_name, entity)| Some((command_name, Component::for_sub_device(entity.sub_device)?)));
+
+    for (command_name, component) in entity_components.chain(profile.components.commands.iter().copied()) {
+      if !self.is_installed(component) && commands.remove(command_name).is_some() {
+        log::debug!("Skipping command {command_name}, {component:?} is not installed.");
+      }
+    }
+  }
+}
@@ -0,0 +1,133 @@
+use std::{
+  env,
+  net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+  time::Duration,
+};
+
+use vcontrol::Device;
+
+const DEFAULT_PORT: u16 = 6053;
+
+const DEFAULT_MAX_STATE_AGE: Duration = Duration::from_secs(5 * 60);
+
+/// Identity and listen address of the ESPHome API server.
+#[derive(Debug, Clone)]
+pub struct Config {
+  pub name: String,
+  pub friendly_name: String,
+  pub manufacturer: String,
+  pub model: String,
+  pub suggested_area: Option<String>,
+  /// Addresses to listen on, tried in order until one can be bound.
+  pub listen_addresses: Vec<SocketAddr>,
+  /// Whether to expose all readable commands, not just the ones with a predefined entity.
+  pub expose_all_commands: bool,
+  /// Maximum age of a value before its entities are reported as having no state.
+  ///
+  /// Climates report unknown temperatures instead. Switches cannot report a missing state, so they keep showing
+  /// their last state.
+  pub max_state_age: Duration,
+}
+
+impl Config {
+  /// Reads the configuration from the `ESPHOME_*` environment variables, falling back to
+  /// defaults derived from the connected device.
+  ///
+  /// Without `ESPHOME_LISTEN_ADDRESS`, the server listens on all IPv6 and IPv4 addresses,
+  /// falling back to IPv4 only if IPv6 is not available.
+  pub fn from_env(device: &Device) -> Self {
+    let var = |key: &str| env::var(key).ok().filter(|value| !value.is_empty());
+
+    let identity = Identity::for_device(device);
+    let friendly_name = var("ESPHOME_FRIENDLY_NAME");
+    let name = var("ESPHOME_NAME").unwrap_or_else(|| match (&friendly_name, identity.name) {
+      (None, Some(name)) => name.to_owned(),
+      (friendly_name, _) => node_name(friendly_name.as_deref().unwrap_or(identity.friendly_name)),
+    });
+    let friendly_name = friendly_name.unwrap_or_else(|| identity.friendly_name.to_owned());
+    let manufacturer = var("ESPHOME_MANUFACTURER").unwrap_or_else(|| "Viessmann".to_owned());
+    let model = var("ESPHOME_MODEL").unwrap_or_else(|| identity.model.to_owned());
+    let suggested_area = var("ESPHOME_SUGGESTED_AREA").or_else(|| identity.suggested_area.map(str::to_owned));
+
+    let listen_addresses = match var("ESPHOME_LISTEN_ADDRESS").map(|addr| addr.parse::<SocketAddr>()) {
+      Some(Ok(addr)) => vec![addr],
+      Some(Err(err)) => {
+        log::warn!("Invalid ESPHOME_LISTEN_ADDRESS, using default: {err}");
+        default_listen_addresses()
+      },
+      None => default_listen_addresses(),
+    };
+
+    let expose_all_commands = match var("ESPHOME_EXPOSE_ALL_COMMANDS").as_deref() {
+      None | Some("0" | "false") => false,
+      Some("1" | "true") => true,
+      Some(value) => {
+        log::warn!("Invalid ESPHOME_EXPOSE_ALL_COMMANDS '{value}', expected 'true' or 'false'.");
+        false
+      },
+    };
+
+    let max_state_age = match var("ESPHOME_MAX_STATE_AGE").map(|secs| secs.parse::<u64>()) {
+      Some(Ok(secs)) if secs > 0 => Duration::from_secs(secs),
+      Some(Ok(_)) => {
+        log::warn!("Invalid ESPHOME_MAX_STATE_AGE, using default: must be positive");
+        DEFAULT_MAX_STATE_AGE
+      },
+      Some(Err(err)) => {
+        log::warn!("Invalid ESPHOME_MAX_STATE_AGE, using default: {err}");
+        DEFAULT_MAX_STATE_AGE
+      },
+      None => DEFAULT_MAX_STATE_AGE,
+    };
+
+    Self {
+      name,
+      friendly_name,
+      manufacturer,
+      model,
+      suggested_area,
+      listen_addresses,
+      expose_all_commands,
+      max_state_age,
+    }
+  }
+}
+
+/// Default identity of a device.
+struct Identity {
+  /// Node name, if it is not derived from the friendly name.
+  name: Option<&'static str>,
+  friendly_name: &'static str,
+  model: &'static str,
+  suggested_area: Option<&'static str>,
+}
+
+impl Identity {
+  fn for_device(device: &Device) -> Self {
+    match device.name() {
+      // Identity used before it was configurable, so that existing installations keep their device and entity IDs.
+      "Ecotronic" => Self {
+        name: Some("vitoligno_300c"),
+        friendly_name: "Vitoligno 300-C",
+        model: "Vitoligno 300-C",
+        suggested_area: Some("Boiler Room"),
+      },
+      name => Self { name: None, friendly_name: name, model: name, suggested_area: None },
+    }
+  }
+}
+
+fn default_listen_addresses() -> Vec<SocketAddr> {
+  vec![SocketAddr::from((Ipv6Addr::UNSPECIFIED, DEFAULT_PORT)), SocketAddr::from((Ipv4Addr::UNSPECIFIED, DEFAULT_PORT))]
+}
+
+/// Converts a friendly name into a node name consisting of lowercase letters, digits and underscores,
+/// e.g. `Vitoligno 300-C` into `vitoligno_300_c`.
+fn node_name(friendly_name: &str) -> String {
+  friendly_name
+    .split(|c: char| !c.is_ascii_alphanumeric())
+    .filter(|part| !part.is_empty())
+    .map(|part| part.to_ascii_lowercase())
+    .collect::<Vec<_>>()
+    .join("_")
+}
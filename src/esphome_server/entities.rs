@@ -1,506 +1,33 @@
 use std::collections::{BTreeMap, HashMap};
+use std::slice;
 
 use esphome_native_api::{
   parser::ProtoMessage,
-  proto::version_2025_12_1::{
+  proto::version_2026_1_5::{
     EntityCategory, ListEntitiesBinarySensorResponse, ListEntitiesDateResponse, ListEntitiesDateTimeResponse,
     ListEntitiesNumberResponse, ListEntitiesSelectResponse, ListEntitiesSensorResponse, ListEntitiesSwitchResponse,
-    ListEntitiesTextSensorResponse, NumberMode,
+    ListEntitiesTextResponse, ListEntitiesTextSensorResponse, NumberMode, TextMode,
   },
 };
 use log::warn;
 use vcontrol::{Command, DataType};
 
 use super::entity::{Entity, EntityType};
+use super::sub_devices::SubDevice;
+use crate::command_poller::QUARANTINED_COMMANDS;
+use crate::schedule::{DAY_SCHEDULE_MAX_LEN, WEEKDAYS, day_schedule_pattern};
 
-const ENTITIES: &[(&str, Entity)] = &[
-  // Buffer
-  ("Ecotronic_Kessel_Ein_Aus", Entity { entity_name: "Boiler", entity_type: EntityType::Switch }),
-  (
-    "Ecotronic_Puffer_Betriebsart",
-    Entity {
-      entity_name: "Buffer Operating Mode",
-      entity_type: EntityType::Select { category: EntityCategory::Config },
-    },
-  ),
-  (
-    "Ecotronic_Pufferladezustand",
-    Entity {
-      entity_name: "Buffer Load State",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "Ecotronic_Puffertemperatur_Mittelwert",
-    Entity {
-      entity_name: "Buffer Mean Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_Puffersoll_Minimal",
-    Entity { entity_name: "Buffer Minimum Temperature", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Puffersoll_Maximal",
-    Entity { entity_name: "Buffer Maximum Temperature", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Puffertemperatur_Soll",
-    Entity {
-      entity_name: "Buffer Desired Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_Puffertemperatur_Ist",
-    Entity {
-      entity_name: "Buffer Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_Puffertemperatur_1",
-    Entity {
-      entity_name: "Buffer Temperature 1",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_Puffertemperatur_2",
-    Entity {
-      entity_name: "Buffer Temperature 2",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_Puffertemperatur_3",
-    Entity {
-      entity_name: "Buffer Temperature 3",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  ("Ecotronic_Puffer_Niveau", Entity { entity_name: "Buffer Niveau", entity_type: EntityType::Number { step: 1.0 } }),
-  ("Ecotronic_Puffer_Neigung", Entity { entity_name: "Buffer Incline", entity_type: EntityType::Number { step: 0.1 } }),
-  // Hot Water
-  (
-    "Ecotronic_Bedien_WW_Solltemperatur",
-    Entity { entity_name: "Hot Water Desired Temperature", entity_type: EntityType::Number { step: 0.1 } },
-  ),
-  // Heating Circuit 1
-  (
-    "Ecotronic_Betriebsart_HK1",
-    Entity { entity_name: "HC1 Operating Mode", entity_type: EntityType::Select { category: EntityCategory::Config } },
-  ),
-  (
-    "Ecotronic_Raumsoll_Normal_HK1",
-    Entity { entity_name: "HC1 Desired Room Temperature", entity_type: EntityType::Number { step: 0.1 } },
-  ),
-  (
-    "Ecotronic_Raumsoll_Reduziert_HK1",
-    Entity { entity_name: "HC1 Desired Reduced Room Temperature", entity_type: EntityType::Number { step: 0.1 } },
-  ),
-  (
-    "VT_SolltemperaturA1M1",
-    Entity {
-      entity_name: "HC1 Desired Flow Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_Heizung_Wunschtemperatur_HK1",
-    Entity {
-      entity_name: "HC1 Desired Heating Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_Vorlauftemperatur_HK1",
-    Entity {
-      entity_name: "HC1 Flow Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  // (
-  //   "Temperatur_2_M1", // Same as `Ecotronic_Vorlauftemperatur_HK1`.
-  //   Entity {
-  //     entity_name: "hc1_temperature_2",
-  //     entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-  //   },
-  // ),
-  (
-    "Ecotronic_HK_Ferienbetrieb_HK1",
-    Entity {
-      entity_name: "HC1 Vacation Mode",
-      entity_type: EntityType::BinarySensor { category: EntityCategory::None },
-    },
-  ),
-  ("Ecotronic_FerienBeginn_HK1", Entity { entity_name: "HC1 Vacaction Mode Begin", entity_type: EntityType::Date }),
-  ("Ecotronic_FerienEnde_HK1", Entity { entity_name: "HC1 Vacation Mode End", entity_type: EntityType::Date }),
-  (
-    "Ecotronic_BedienPartybetriebM1",
-    Entity { entity_name: "HC1 Desired Party Mode Temperature", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  ("Ecotronic_BedienSparbetrieb_HK1", Entity { entity_name: "HC1 Energy Saver Mode", entity_type: EntityType::Switch }),
-  ("Ecotronic_BedienNiveauM1", Entity { entity_name: "HC1 Niveau", entity_type: EntityType::Number { step: 1.0 } }),
-  ("Ecotronic_BedienNeigung_HK1", Entity { entity_name: "HC1 Incline", entity_type: EntityType::Number { step: 0.1 } }),
-  (
-    "Ecotronic_Pumpe_HK1",
-    Entity { entity_name: "HC1 Pump", entity_type: EntityType::BinarySensor { category: EntityCategory::Diagnostic } },
-  ),
-  (
-    "Ecotronic_Mischerposition_HK1",
-    Entity {
-      entity_name: "HC1 Mixer Position",
-      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "Ecotronic_Heizungstatus",
-    Entity {
-      entity_name: "HC1 Heating Status",
-      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
-    },
-  ),
-  // Heating Circuit 2
-  (
-    "Ecotronic_Betriebsart_HK2",
-    Entity { entity_name: "HC2 Operating Mode", entity_type: EntityType::Select { category: EntityCategory::Config } },
-  ),
-  (
-    "Ecotronic_Raumsoll_Normal_HK2",
-    Entity { entity_name: "HC2 Desired Room Temperature", entity_type: EntityType::Number { step: 0.1 } },
-  ),
-  (
-    "Ecotronic_Raumsoll_Reduziert_HK2",
-    Entity { entity_name: "HC2 Desired Reduced Room Temperature", entity_type: EntityType::Number { step: 0.1 } },
-  ),
-  (
-    "Ecotronic_Vorlauftemperatur_HK2",
-    Entity {
-      entity_name: "HC2 Flow Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  // (
-  //   "Temperatur_2_M2", // Same as `Ecotronic_Vorlauftemperatur_HK2`.
-  //   Entity { entity_name: "hc2_temperature_2", entity_type: EntityType::Sensor { accuracy_decimals: 1 },
-  //     category: EntityCategory::None },
-  // ),
-  (
-    "Ecotronic_Heizung_Wunschtemperatur_HK2",
-    Entity {
-      entity_name: "HC2 Desired Heating Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "VT_SolltemperaturM2",
-    Entity {
-      entity_name: "HC2 Desired Flow Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_HK_Ferienbetrieb_HK2",
-    Entity {
-      entity_name: "HC2 Vacation Mode",
-      entity_type: EntityType::BinarySensor { category: EntityCategory::None },
-    },
-  ),
-  ("Ecotronic_FerienBeginn_HK2", Entity { entity_name: "HC2 Vacation Mode Begin", entity_type: EntityType::Date }),
-  ("Ecotronic_FerienEnde_HK2", Entity { entity_name: "HC2 Vacation Mode End", entity_type: EntityType::Date }),
-  (
-    "Ecotronic_BedienPartybetriebM2",
-    Entity { entity_name: "HC2 Desired Party Mode Temperature", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  ("Ecotronic_BedienSparbetrieb_HK2", Entity { entity_name: "HC2 Energy Saver Mode", entity_type: EntityType::Switch }),
-  ("Ecotronic_BedienNiveauM2", Entity { entity_name: "HC2 Niveau", entity_type: EntityType::Number { step: 1.0 } }),
-  ("Ecotronic_BedienNeigung_HK2", Entity { entity_name: "HC2 Incline", entity_type: EntityType::Number { step: 0.1 } }),
-  (
-    "Ecotronic_Pumpe_HK2",
-    Entity { entity_name: "HC2 Pump", entity_type: EntityType::BinarySensor { category: EntityCategory::Diagnostic } },
-  ),
-  (
-    "Ecotronic_Mischerposition_HK2",
-    Entity {
-      entity_name: "HC2 Mixer Position",
-      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "Ecotronic_Heizungstatus_HK2",
-    Entity {
-      entity_name: "HC2 Heating Status",
-      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
-    },
-  ),
-  // Boiler
-  (
-    "Ecotronic_Kesseltype",
-    Entity { entity_name: "Boiler Type", entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic } },
-  ),
-  (
-    "Ecotronic_Kesselstatus",
-    Entity {
-      entity_name: "Boiler Status",
-      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "SC100_KesselIsttemperatur",
-    Entity {
-      entity_name: "Boiler Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_Abgastemperatur",
-    Entity {
-      entity_name: "Boiler Exhaust Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "SC100_Lambdasonde",
-    Entity {
-      entity_name: "Boiler Exhaust Rest O2",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "SC100_PositionPrimaerluftklappe",
-    Entity {
-      entity_name: "Boiler Primary Flap Position",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "SC100_PositionSekundaerluftklappe",
-    Entity {
-      entity_name: "Boiler Secondary Flap Position",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "Ecotronic_Kesselsolltemperatur",
-    Entity { entity_name: "Boiler Desired Temperature", entity_type: EntityType::Number { step: 0.1 } },
-  ),
-  (
-    "Ecotronic_Kessel_Rücklauf_Soll",
-    Entity { entity_name: "Boiler Desired Return Temperature", entity_type: EntityType::Number { step: 0.1 } },
-  ),
-  (
-    "Ecotronic_Kesselrücklauftemperatur",
-    Entity {
-      entity_name: "Boiler Return Temperature",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  ("Ecotronic_Kesselstarts", Entity { entity_name: "Boiler Starts", entity_type: EntityType::Number { step: 1.0 } }),
-  (
-    "Ecotronic_Betriebsstunden_Volllast",
-    Entity {
-      entity_name: "Operating Hours Full Load",
-      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "Ecotronic_Betriebsstunden_Teillast",
-    Entity {
-      entity_name: "Operating Hours Partial Load",
-      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "Ecotronic_Betriebsstunden_Kessel",
-    Entity {
-      entity_name: "Boiler Operating Hours",
-      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "Ecotronic_Betriebsstunden_Einschubschnecke",
-    Entity {
-      entity_name: "Pellet Worm Drive Operating Hours",
-      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "Ecotronic_Betriebsminuten_Einschubschnecke",
-    Entity {
-      entity_name: "Pellet Worm Drive Operating Minutes",
-      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
-    },
-  ),
-  // Ash
-  (
-    "Ecotronic_Füllstand_Entaschung",
-    Entity {
-      entity_name: "Ash Level",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  // Pellets
-  (
-    "Ecotronic_Brennstofflager_Füllstand",
-    Entity { entity_name: "Pellet Silo Level", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Brennstofflager_Minimalbegrenzung",
-    Entity { entity_name: "Pellet Silo Minimum Level", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Brennstofflager_Maximalbegrenzung",
-    Entity { entity_name: "Pellet Silo Maximum Level", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Füllstand_Pellet",
-    Entity {
-      entity_name: "Pellet Level",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_Brennstoffverbrauch",
-    Entity {
-      entity_name: "Pellet Consumption per Hour",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "NRF_Brennstoffverbrauch_Bedien",
-    Entity {
-      entity_name: "Pellet Consumption",
-      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_Pellet_Leerfahrzeit",
-    Entity { entity_name: "Pellet Hopper Empty Time", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  // Outside Temperature
-  (
-    "NRF_TemperaturFehler_ATS",
-    Entity {
-      entity_name: "Outside Temperature Status",
-      entity_type: EntityType::BinarySensor { category: EntityCategory::None },
-    },
-  ),
-  (
-    "NRF_TiefpassTemperaturwert_ATS",
-    Entity {
-      entity_name: "Outside Temperature Lowpass",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Gemischte_AT",
-    Entity {
-      entity_name: "Outside Temperature Mixed",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  (
-    "Ecotronic_Gemischte_AT",
-    Entity {
-      entity_name: "Outside Temperature Mixed 2",
-      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
-    },
-  ),
-  // Changeover Unit
-  (
-    "Ecotronic_Umschalteinheit_Sonde",
-    Entity {
-      entity_name: "Changeover Unit Current Probe",
-      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "Ecotronic_Umschalteinheit_Sonde_Laufzeit",
-    Entity {
-      entity_name: "Changeover Unit Current Probe Runtime",
-      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::Diagnostic },
-    },
-  ),
-  (
-    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_1",
-    Entity { entity_name: "Changeover Unit Probe 1 Runtime", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_2",
-    Entity { entity_name: "Changeover Unit Probe 2 Runtime", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_3",
-    Entity { entity_name: "Changeover Unit Probe 3 Runtime", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_4",
-    Entity { entity_name: "Changeover Unit Probe 4 Runtime", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_5",
-    Entity { entity_name: "Changeover Unit Probe 5 Runtime", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_6",
-    Entity { entity_name: "Changeover Unit Probe 6 Runtime", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_7",
-    Entity { entity_name: "Changeover Unit Probe 7 Runtime", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_8",
-    Entity { entity_name: "Changeover Unit Probe 8 Runtime", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_Soll",
-    Entity { entity_name: "Changeover Unit Desired Probe Runtime", entity_type: EntityType::Number { step: 1.0 } },
-  ),
-  (
-    "Ecotronic_Betriebsstunden_Saugmodul",
-    Entity {
-      entity_name: "Changeover Unit Operating Hours",
-      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
-    },
-  ),
-  // Errors
-  (
-    "ecnsysEventType~ErrorIndex",
-    Entity { entity_name: "Error", entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic } },
-  ),
-  (
-    "ecnsysEventType~Error",
-    Entity {
-      entity_name: "Error History",
-      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
-    },
-  ),
-  ("Ecotronic_Fehler_Quittierung", Entity { entity_name: "Error Acknowledgement", entity_type: EntityType::Switch }),
-  (
-    "NRF_Uhrzeit",
-    Entity { entity_name: "System Time", entity_type: EntityType::DateTime { category: EntityCategory::Config } },
-  ),
-];
-
-fn unit_to_device_class(unit: &str, entity_name: &str) -> &'static str {
-  match unit {
-    "" => {
-      warn!("Unknown device class for entity without unit: {entity_name}");
-      ""
-    },
+/// Returns the device class for the given unit, or `None` if it is unknown.
+fn unit_to_device_class(unit: &str) -> Option<&'static str> {
+  Some(match unit {
     "°C" | "K" => "temperature",
     "kg" => "weight",
     "h" | "min" | "s" => "duration",
     "kg/h" => "volume_flow_rate",
+    "W" => "power",
     "%" => "",
-    unit => {
-      warn!("Unknown device class for entity {entity_name} unit: {unit}");
-      "None"
-    },
-  }
+    _ => return None,
+  })
 }
 
 pub enum MultiEntity {
@@ -519,16 +46,77 @@ impl MultiEntity {
       ProtoMessage::ListEntitiesTextSensorResponse(res) => res.key,
       ProtoMessage::ListEntitiesSwitchResponse(res) => res.key,
       ProtoMessage::ListEntitiesSelectResponse(res) => res.key,
+      ProtoMessage::ListEntitiesTextResponse(res) => res.key,
+      _ => u32::MAX,
+    }
+  }
+
+  /// Returns the ID of the sub-device the given entity belongs to.
+  pub fn entity_device_id(entity: &ProtoMessage) -> u32 {
+    match entity {
+      ProtoMessage::ListEntitiesBinarySensorResponse(res) => res.device_id,
+      ProtoMessage::ListEntitiesSensorResponse(res) => res.device_id,
+      ProtoMessage::ListEntitiesNumberResponse(res) => res.device_id,
+      ProtoMessage::ListEntitiesDateResponse(res) => res.device_id,
+      ProtoMessage::ListEntitiesDateTimeResponse(res) => res.device_id,
+      ProtoMessage::ListEntitiesTextSensorResponse(res) => res.device_id,
+      ProtoMessage::ListEntitiesSwitchResponse(res) => res.device_id,
+      ProtoMessage::ListEntitiesSelectResponse(res) => res.device_id,
+      ProtoMessage::ListEntitiesTextResponse(res) => res.device_id,
       _ => u32::MAX,
     }
   }
 
+  /// Returns the ID of the sub-device all entities belong to.
+  pub fn device_id(&self) -> u32 {
+    match self {
+      Self::Single(entity) => Self::entity_device_id(entity),
+      Self::Multiple(entities) => entities.first().map(Self::entity_device_id).unwrap_or(u32::MAX),
+    }
+  }
+
+  /// Returns whether this entity is disabled by default, i.e. whether it was generated for a command not listed in the
+  /// device's profile.
+  pub fn is_disabled_by_default(&self) -> bool {
+    match self {
+      Self::Single(entity) => match entity.as_ref() {
+        ProtoMessage::ListEntitiesSensorResponse(res) => res.disabled_by_default,
+        ProtoMessage::ListEntitiesDateTimeResponse(res) => res.disabled_by_default,
+        ProtoMessage::ListEntitiesTextSensorResponse(res) => res.disabled_by_default,
+        _ => false,
+      },
+      Self::Multiple(_) => false,
+    }
+  }
+
+  /// Returns whether this entity, or one of its entities, is identified by the given device ID and key.
+  pub fn matches(&self, device_id: u32, key: u32) -> bool {
+    self.device_id() == device_id && self.position(key).is_some()
+  }
+
   pub fn key(&self) -> u32 {
     match self {
       Self::Single(entity) => Self::map_entity_to_key(entity),
       Self::Multiple(entities) => entities.first().map(Self::map_entity_to_key).unwrap_or(u32::MAX),
     }
   }
+
+  pub fn keys(&self) -> impl Iterator<Item = u32> + '_ {
+    let entities = match self {
+      Self::Single(entity) => slice::from_ref(entity.as_ref()),
+      Self::Multiple(entities) => entities.as_slice(),
+    };
+
+    entities.iter().map(Self::map_entity_to_key)
+  }
+
+  /// Returns the index of the entity with the given key.
+  pub fn position(&self, key: u32) -> Option<usize> {
+    match self {
+      Self::Single(entity) => (Self::map_entity_to_key(entity) == key).then_some(0),
+      Self::Multiple(entities) => entities.iter().position(|entity| Self::map_entity_to_key(entity) == key),
+    }
+  }
 }
 
 impl From<ProtoMessage> for MultiEntity {
@@ -537,36 +125,98 @@ impl From<ProtoMessage> for MultiEntity {
   }
 }
 
-pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&'static str, MultiEntity> {
-  let device_id = 0;
+/// Returns a read-only diagnostic entity for a command not listed in the device's profile, or `None` if
+/// the command's value cannot be represented by a single entity.
+fn generated_entity(command_name: &'static str, command: &Command) -> Option<Entity> {
+  if !command.access_mode().is_read() || command.block_count().is_some() {
+    return None;
+  }
+
+  let category = EntityCategory::Diagnostic;
+  let entity_type = match command.data_type() {
+    DataType::Int | DataType::Byte if command.mapping().is_some() => EntityType::TextSensor { category },
+    DataType::Int | DataType::Byte => EntityType::Sensor { accuracy_decimals: 0, category },
+    DataType::Double => EntityType::Sensor { accuracy_decimals: 1, category },
+    DataType::DateTime => EntityType::DateTime { category },
+    DataType::String
+    | DataType::Date
+    | DataType::DeviceId
+    | DataType::DeviceIdF0
+    | DataType::ByteArray
+    | DataType::ErrorIndex => EntityType::TextSensor { category },
+    DataType::CircuitTimes | DataType::Error => return None,
+  };
+
+  Some(Entity { sub_device: SubDevice::Main, entity_name: command_name, entity_type })
+}
 
+/// Returns the entities for all supported commands listed in the given profile.
+///
+/// With `expose_all_commands`, an entity is also generated for every other readable command. These
+/// entities are disabled by default and have keys following the listed ones. Their commands are not polled, but
+/// their values are sent whenever they are read, e.g. when requested using the refresh service.
+///
+/// The last entity lists the commands which are no longer polled since they keep failing.
+pub fn entities(
+  commands: &HashMap<&'static str, &'static Command>,
+  profile: &[(&'static str, Entity)],
+  expose_all_commands: bool,
+) -> HashMap<&'static str, MultiEntity> {
   let mut entity_map = HashMap::new();
 
   let mut key = 0;
 
-  for &(command_name, ref entity) in ENTITIES {
+  let listed_entities = profile.iter().map(|&(command_name, entity)| (command_name, entity, false));
+
+  let mut generated_entities = if expose_all_commands {
+    commands
+      .iter()
+      .filter(|(command_name, _)| !profile.iter().any(|(name, _)| name == *command_name))
+      .filter_map(|(&command_name, command)| Some((command_name, generated_entity(command_name, command)?, true)))
+      .collect()
+  } else {
+    vec![]
+  };
+  generated_entities.sort_by_key(|&(command_name, _, _)| command_name);
+
+  for (command_name, entity, generated) in listed_entities.chain(generated_entities) {
     let Some(command) = commands.get(command_name) else {
-      log::warn!("Command '{command_name}' not found.");
+      log::debug!("Command '{command_name}' not supported by device.");
       continue;
     };
     let unit = command.unit().unwrap_or_default();
 
+    let device_id = entity.sub_device.id();
     let name = entity.entity_name.to_owned();
-    let entity_id = entity.entity_name.to_lowercase().split(' ').collect::<Vec<&str>>().join("_");
+    let entity_id =
+      entity.entity_name.to_lowercase().split(|c: char| !c.is_ascii_alphanumeric()).collect::<Vec<&str>>().join("_");
     let device_class = match entity.entity_type {
-      EntityType::Switch | EntityType::BinarySensor { .. } => "",
+      EntityType::Switch | EntityType::BinarySensor { .. } | EntityType::Schedule => "",
       EntityType::Date => "date",
       EntityType::DateTime { .. } => "timestamp",
       EntityType::Select { .. } => "enum",
       EntityType::TextSensor { .. } => "",
-      _ => unit_to_device_class(unit, &entity_id),
+      _ => unit_to_device_class(unit).unwrap_or_else(|| {
+        // Generated entities are expected to have units without a device class.
+        if !generated {
+          if unit.is_empty() {
+            warn!("Unknown device class for entity without unit: {entity_id}");
+          } else {
+            warn!("Unknown device class for entity {entity_id} unit: {unit}");
+          }
+        }
+        ""
+      }),
     };
 
-    if command.access_mode().is_write() {
-      assert_eq!(entity.category(), EntityCategory::Config, "Wrong category for {}", entity.entity_name);
-    } else {
-      assert_ne!(entity.category(), EntityCategory::Config, "Wrong category for {}", entity.entity_name);
-    };
+    // Generated entities are always read-only.
+    if !generated {
+      if command.access_mode().is_write() {
+        assert_eq!(entity.category(), EntityCategory::Config, "Wrong category for {}", entity.entity_name);
+      } else {
+        assert_ne!(entity.category(), EntityCategory::Config, "Wrong category for {}", entity.entity_name);
+      };
+    }
 
     match entity.entity_type {
       EntityType::Number { step } => {
@@ -583,7 +233,7 @@ pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&
             min_value: command.lower_bound().map(|v| v as f32).unwrap_or(f32::MIN),
             max_value: command.upper_bound().map(|v| v as f32).unwrap_or(f32::MAX),
             step,
-            disabled_by_default: false,
+            disabled_by_default: generated,
             entity_category: EntityCategory::Config as i32,
             mode: NumberMode::Box as i32,
           })
@@ -606,7 +256,7 @@ pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&
             state_class: 1, // SensorStateClass::StateClassMeasurement as i32 // TODO
             #[allow(deprecated)]
             legacy_last_reset_type: 0, // SensorLastResetType::LastResetNone as i32      // TODO
-            disabled_by_default: false,
+            disabled_by_default: generated,
             entity_category: category as i32, // EntityCategory::None as i32 // TODO
           })
           .into(),
@@ -623,7 +273,7 @@ pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&
             icon: "".into(),         // TODO
             device_class: "".into(), // TODO
             is_status_binary_sensor: false,
-            disabled_by_default: false,
+            disabled_by_default: generated,
             entity_category: category as i32,
           })
           .into(),
@@ -639,7 +289,7 @@ pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&
             name,
             icon: "".into(),         // TODO
             device_class: "".into(), // TODO
-            disabled_by_default: false,
+            disabled_by_default: generated,
             entity_category: EntityCategory::Config as i32,
             assumed_state: false,
           })
@@ -655,7 +305,7 @@ pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&
             key,
             name,
             icon: "mdi:calendar".into(), // TODO
-            disabled_by_default: false,
+            disabled_by_default: generated,
             entity_category: EntityCategory::Config as i32,
           })
           .into(),
@@ -670,7 +320,7 @@ pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&
             key,
             name,
             icon: "".into(), // TODO
-            disabled_by_default: false,
+            disabled_by_default: generated,
             entity_category: category as i32,
             options: {
               let mapping = commands[command_name].mapping().unwrap();
@@ -694,12 +344,43 @@ pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&
             key,
             name,
             icon: "mdi:calendar-clock".into(),
-            disabled_by_default: false,
+            disabled_by_default: generated,
             entity_category: category as i32,
           })
           .into(),
         );
       },
+      EntityType::Schedule => {
+        entity_map.insert(
+          command_name,
+          MultiEntity::Multiple(
+            WEEKDAYS
+              .iter()
+              .map(|(weekday_key, weekday)| {
+                let entity = ProtoMessage::ListEntitiesTextResponse(ListEntitiesTextResponse {
+                  device_id,
+                  object_id: format!("{entity_id}_{weekday_key}"),
+                  key,
+                  name: format!("{name} {weekday}"),
+                  icon: "mdi:calendar-clock".into(),
+                  disabled_by_default: generated,
+                  entity_category: EntityCategory::Config as i32,
+                  min_length: 0,
+                  max_length: DAY_SCHEDULE_MAX_LEN as u32,
+                  pattern: day_schedule_pattern(),
+                  mode: TextMode::Text as i32,
+                });
+                key += 1;
+
+                entity
+              })
+              .collect(),
+          ),
+        );
+
+        // `key` already incremented.
+        continue;
+      },
       EntityType::TextSensor { category } => {
         let command = &commands[command_name];
 
@@ -718,7 +399,7 @@ pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&
                       key,
                       name: format!("{name} {i} Time"),
                       icon: "".into(), // TODO
-                      disabled_by_default: false,
+                      disabled_by_default: generated,
                       entity_category: category as i32,
                     }));
 
@@ -732,7 +413,7 @@ pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&
                     name: format!("{name} {i} Message"),
                     icon: "".into(),         // TODO
                     device_class: "".into(), // TODO
-                    disabled_by_default: false,
+                    disabled_by_default: generated,
                     entity_category: category as i32,
                   }));
                   key += 1;
@@ -755,7 +436,7 @@ pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&
               name,
               icon: "".into(),         // TODO
               device_class: "".into(), // TODO
-              disabled_by_default: false,
+              disabled_by_default: generated,
               entity_category: category as i32,
             })
             .into(),
@@ -767,5 +448,20 @@ pub fn entities(commands: &HashMap<&'static str, &'static Command>) -> HashMap<&
     key += 1;
   }
 
+  entity_map.insert(
+    QUARANTINED_COMMANDS,
+    ProtoMessage::ListEntitiesTextSensorResponse(ListEntitiesTextSensorResponse {
+      device_id: SubDevice::Main.id(),
+      object_id: QUARANTINED_COMMANDS.to_owned(),
+      key,
+      name: "Quarantined Commands".into(),
+      icon: "mdi:alert-octagon".into(),
+      device_class: "".into(),
+      disabled_by_default: false,
+      entity_category: EntityCategory::Diagnostic as i32,
+    })
+    .into(),
+  );
+
   entity_map
 }
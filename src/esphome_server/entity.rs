@@ -1,4 +1,6 @@
-use esphome_native_api::proto::version_2025_12_1::EntityCategory;
+use esphome_native_api::proto::version_2026_1_5::EntityCategory;
+
+use crate::esphome_server::sub_devices::SubDevice;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EntityType {
@@ -10,9 +12,12 @@ pub enum EntityType {
   Select { category: EntityCategory },
   Switch,
   Date,
+  Schedule,
 }
 
+#[derive(Debug, Clone, Copy)]
 pub struct Entity {
+  pub sub_device: SubDevice,
   pub entity_name: &'static str,
   pub entity_type: EntityType,
 }
@@ -28,6 +33,7 @@ impl Entity {
       EntityType::Select { category } => category,
       EntityType::Switch => EntityCategory::Config,
       EntityType::Date => EntityCategory::Config,
+      EntityType::Schedule => EntityCategory::Config,
     }
   }
 }
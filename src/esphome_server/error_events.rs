@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use esphome_native_api::parser::ProtoMessage;
+use esphome_native_api::proto::version_2026_1_5::{
+  EntityCategory, EventResponse, ListEntitiesEventResponse, ListEntitiesTextSensorResponse, TextSensorStateResponse,
+};
+use vcontrol::{Device, Value};
+
+/// Command returning the codes of all currently active errors.
+pub const ACTIVE_ERRORS_COMMAND: &str = "ecnsysEventType~ErrorIndex";
+
+/// Event entities which are triggered when an error is raised or cleared.
+///
+/// The event type is the error code followed by the error text, e.g. `A3 Flue gas temperature sensor fault`.
+/// Since event types must be declared in advance, the time at which the last raised or cleared error was detected
+/// is reported by an additional timestamp sensor for each event entity.
+pub struct ErrorEvents {
+  raised_key: u32,
+  cleared_key: u32,
+  raised_at_key: u32,
+  cleared_at_key: u32,
+  event_types: BTreeMap<u8, String>,
+}
+
+impl ErrorEvents {
+  /// Number of keys used by the entities.
+  const KEY_COUNT: u32 = 4;
+
+  /// Creates the event entities for the errors known for the given device, allocating keys from `next_key`.
+  pub fn new(device: &Device, next_key: &mut u32) -> Self {
+    let key = *next_key;
+    *next_key += Self::KEY_COUNT;
+
+    let event_types = device
+      .errors()
+      .entries()
+      .filter_map(|(&code, text)| Some((u8::try_from(code).ok()?, format!("{code:02X} {text}"))))
+      .collect();
+
+    Self { raised_key: key, cleared_key: key + 1, raised_at_key: key + 2, cleared_at_key: key + 3, event_types }
+  }
+
+  pub fn entities(&self, device_id: u32) -> [ListEntitiesEventResponse; 2] {
+    let entity = |object_id: &str, key, name: &str| ListEntitiesEventResponse {
+      device_id,
+      object_id: object_id.to_owned(),
+      key,
+      name: name.to_owned(),
+      icon: "mdi:alert-circle".into(),
+      disabled_by_default: false,
+      entity_category: EntityCategory::None as i32,
+      device_class: "".into(),
+      event_types: self.event_types.values().cloned().collect(),
+    };
+
+    [
+      entity("error_raised", self.raised_key, "Error Raised"),
+      entity("error_cleared", self.cleared_key, "Error Cleared"),
+    ]
+  }
+
+  pub fn timestamp_entities(&self, device_id: u32) -> [ListEntitiesTextSensorResponse; 2] {
+    let entity = |object_id: &str, key, name: &str| ListEntitiesTextSensorResponse {
+      device_id,
+      object_id: object_id.to_owned(),
+      key,
+      name: name.to_owned(),
+      icon: "mdi:clock-alert".into(),
+      device_class: "timestamp".into(),
+      disabled_by_default: false,
+      entity_category: EntityCategory::Diagnostic as i32,
+    };
+
+    [
+      entity("error_raised_at", self.raised_at_key, "Error Raised At"),
+      entity("error_cleared_at", self.cleared_at_key, "Error Cleared At"),
+    ]
+  }
+
+  /// Compares the active errors with the previously active errors and returns an event for each raised and cleared
+  /// error, followed by the updated timestamp sensors.
+  ///
+  /// No events are returned without previously active errors, since it is unknown when these errors were raised.
+  pub fn update(
+    &self,
+    device_id: u32,
+    previous: Option<&Value>,
+    value: &Value,
+    read_at: DateTime<Utc>,
+  ) -> Vec<ProtoMessage> {
+    fn active_errors(value: &Value) -> Option<&[u8]> {
+      match value {
+        Value::ByteArray(errors) => Some(errors),
+        value => {
+          log::warn!("Unsupported value for {ACTIVE_ERRORS_COMMAND}: {value:?}");
+          None
+        },
+      }
+    }
+
+    let Some(errors) = active_errors(value) else { return vec![] };
+    let Some(previous_errors) = previous.and_then(active_errors) else { return vec![] };
+
+    let raised = errors.iter().filter(|code| !previous_errors.contains(code)).map(|&code| (self.raised_key, code));
+    let cleared = previous_errors.iter().filter(|code| !errors.contains(code)).map(|&code| (self.cleared_key, code));
+
+    let events = raised
+      .chain(cleared)
+      .filter_map(|(key, code)| {
+        let Some(event_type) = self.event_types.get(&code) else {
+          log::warn!("Unknown error code {code:02X}.");
+          return None;
+        };
+
+        Some(EventResponse { device_id, key, event_type: event_type.clone() })
+      })
+      .collect::<Vec<_>>();
+
+    let timestamp = |key| TextSensorStateResponse { device_id, key, state: read_at.to_rfc3339(), missing_state: false };
+    let timestamps = [(self.raised_key, self.raised_at_key), (self.cleared_key, self.cleared_at_key)]
+      .into_iter()
+      .filter(|&(event_key, _)| events.iter().any(|event| event.key == event_key))
+      .map(|(_, key)| ProtoMessage::TextSensorStateResponse(timestamp(key)));
+
+    events.iter().cloned().map(ProtoMessage::EventResponse).chain(timestamps).collect()
+  }
+}
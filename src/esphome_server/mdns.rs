@@ -0,0 +1,44 @@
+use mac_address::MacAddress;
+use mdns_sd::{ServiceDaemon, ServiceInfo};
+
+const SERVICE_TYPE: &str = "_esphomelib._tcp.local.";
+
+/// ESPHome version matching the API protocol version used by the server.
+const ESPHOME_VERSION: &str = "2025.12.1";
+
+/// Encryption scheme advertised by ESPHome nodes with API encryption enabled.
+const API_ENCRYPTION: &str = "Noise_NNpsk0_25519_ChaChaPoly_SHA256";
+
+/// Advertises the ESPHome API server via mDNS so it can be discovered by Home Assistant.
+///
+/// The returned daemon keeps responding to queries until it is shut down.
+pub fn advertise(
+  name: &str,
+  friendly_name: &str,
+  mac_address: MacAddress,
+  port: u16,
+  encrypted: bool,
+) -> Result<ServiceDaemon, mdns_sd::Error> {
+  let daemon = ServiceDaemon::new()?;
+
+  let mac = mac_address.bytes().iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+  let mut properties = vec![
+    ("friendly_name", friendly_name),
+    ("version", ESPHOME_VERSION),
+    ("mac", &mac),
+    ("platform", "host"),
+    ("network", "ethernet"),
+  ];
+  if encrypted {
+    properties.push(("api_encryption", API_ENCRYPTION));
+  }
+
+  let host_name = format!("{name}.local.");
+  let service_info = ServiceInfo::new(SERVICE_TYPE, name, &host_name, (), port, &properties[..])?.enable_addr_auto();
+
+  log::info!("Advertising {} via mDNS.", service_info.get_fullname());
+  daemon.register(service_info)?;
+
+  Ok(daemon)
+}
@@ -1,56 +1,181 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::iter;
 use std::net::SocketAddr;
-use std::sync::{Arc, Weak};
+use std::sync::Arc;
 use std::{env, io};
 
 use esphome_native_api::esphomeapi::EspHomeApi;
 use esphome_native_api::parser::ProtoMessage;
-use esphome_native_api::proto::version_2025_12_1::{
-  ListEntitiesDoneResponse, ListEntitiesRequest, SubscribeHomeAssistantStatesRequest,
-  SubscribeHomeassistantServicesRequest, SubscribeStatesRequest,
+use esphome_native_api::proto::version_2026_1_5::{
+  ButtonCommandRequest, ListEntitiesDoneResponse, ListEntitiesRequest, LogLevel, SubscribeHomeAssistantStatesRequest,
+  SubscribeHomeassistantServicesRequest, SubscribeLogsRequest, SubscribeStatesRequest,
 };
 
 use mac_address::get_mac_address;
-use tokio::net::TcpSocket;
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::net::TcpListener;
 use tokio::sync::broadcast;
 use tokio::sync::oneshot::{self, Receiver, Sender};
 use tokio::task::JoinHandle;
-use vcontrol::{Command, VControl};
+use vcontrol::Command;
 
+use crate::bus::{Bus, Priority};
+use crate::command_poller::{QUARANTINED_COMMANDS, Refresher, Store, Subscriptions};
+use crate::esphome_server::climate::Climate;
+use crate::esphome_server::components::Components;
 use crate::esphome_server::entities::MultiEntity;
-use crate::esphome_server::server::{handle_number_command, handle_switch_command};
-
+use crate::esphome_server::error_events::{ACTIVE_ERRORS_COMMAND, ErrorEvents};
+use crate::esphome_server::refresh_button::RefreshButton;
+use crate::esphome_server::server::{
+  handle_climate_command, handle_execute_service, handle_number_command, handle_switch_command, handle_text_command,
+  handle_water_heater_command,
+};
+use crate::esphome_server::sub_devices::SubDevice;
+use crate::esphome_server::water_heater::WaterHeater;
+use crate::logger;
+use crate::time_zone::TimeZone;
+
+mod climate;
+mod components;
+mod config;
+pub use config::Config;
 mod entities;
 mod entity;
+mod error_events;
+mod mdns;
+mod profiles;
+mod refresh_button;
 mod server;
-use server::{handle_date_command, handle_date_time_command, send_state_loop};
+mod services;
+mod sub_devices;
+mod water_heater;
+use server::{handle_date_command, handle_date_time_command, level_filter, send_log_loop, send_state_loop};
+
+fn bind(addr: SocketAddr) -> io::Result<TcpListener> {
+  let socket = Socket::new(Domain::for_address(addr), Type::STREAM, Some(Protocol::TCP))?;
+  if addr.is_ipv6() {
+    // Accept IPv4 connections as well, regardless of the system default.
+    socket.set_only_v6(false)?;
+  }
+  socket.set_reuse_address(true)?;
+  socket.set_nonblocking(true)?;
+  socket.bind(&addr.into())?;
+  socket.listen(128)?;
+  TcpListener::from_std(socket.into())
+}
 
 pub async fn start(
-  vcontrol_weak: Weak<tokio::sync::Mutex<VControl>>,
-  commands: HashMap<&'static str, &'static Command>,
-  vcontrol_rx: broadcast::Receiver<(&'static str, vcontrol::Value)>,
+  bus: Bus,
+  mut commands: HashMap<&'static str, &'static Command>,
+  store: Store,
+  refresher: Refresher,
+  subscriptions: Subscriptions,
+  time_zone: TimeZone,
+  config: Config,
 ) -> (impl Future<Output = Result<(), io::Error>>, Sender<()>, Receiver<()>) {
   let (server_stopped_tx, server_stopped_rx) = oneshot::channel();
   let (server_stop_tx, server_stop_rx) = oneshot::channel();
 
-  let entities = entities::entities(&commands);
+  let device = bus.device();
+  let profile = profiles::profile(device);
+  let components = {
+    let mut vcontrol = bus.acquire(Priority::Read).await.expect("Bus closed");
+    Components::detect(&mut vcontrol, &profile.components).await
+  };
+  components.retain_installed(&mut commands, profile);
 
-  let addr = SocketAddr::from(([0, 0, 0, 0], 6053));
-  let socket = TcpSocket::new_v4().unwrap();
-  socket.set_reuseaddr(true).unwrap();
-  socket.bind(addr).unwrap();
+  let expose_all_commands = if profile.entities.is_empty() {
+    log::info!("No entity profile for device {}, exposing all commands.", device.name());
+    true
+  } else {
+    config.expose_all_commands
+  };
 
-  let listener = socket.listen(128).unwrap();
-  log::debug!("Listening on: {}", addr);
+  let entities = entities::entities(&commands, profile.entities, expose_all_commands);
+  let mut next_key = entities.values().filter_map(|entity| entity.keys().max()).max().map_or(0, |key| key + 1);
+  let climates = climate::climates(&commands, &mut next_key);
+  let water_heater = water_heater::water_heater(&commands, &mut next_key);
+  let error_events = commands.contains_key(ACTIVE_ERRORS_COMMAND).then(|| ErrorEvents::new(device, &mut next_key));
+
+  // Commands which need to be polled while a client is subscribed to states, with the ID of the device they belong to.
+  // Commands of entities disabled by default are only read when requested explicitly using the refresh service.
+  let device_commands = entities
+    .iter()
+    .filter(|&(&command_name, entity)| command_name != QUARANTINED_COMMANDS && !entity.is_disabled_by_default())
+    .map(|(&command_name, entity)| (entity.device_id(), command_name))
+    .chain(
+      climates
+        .iter()
+        .flat_map(|climate| climate.command_names().map(|command_name| (climate.sub_device().id(), command_name))),
+    )
+    .chain(water_heater.iter().flat_map(|water_heater| {
+      water_heater.command_names().map(|command_name| (water_heater.sub_device().id(), command_name))
+    }))
+    .chain(error_events.as_ref().map(|_| (SubDevice::Main.id(), ACTIVE_ERRORS_COMMAND)))
+    .collect::<BTreeSet<_>>();
+  let consumed_commands = device_commands.iter().map(|&(_, command_name)| command_name).collect::<BTreeSet<_>>();
+
+  let Config { name, friendly_name, manufacturer, model, suggested_area, listen_addresses, max_state_age, .. } = config;
+
+  let sub_devices = profile
+    .entities
+    .iter()
+    .filter(|(command_name, _)| commands.contains_key(command_name))
+    .map(|(_, entity)| entity.sub_device)
+    .chain(climates.iter().map(Climate::sub_device))
+    .chain(water_heater.iter().map(WaterHeater::sub_device))
+    .collect::<BTreeSet<_>>();
+
+  // Each device gets a button refreshing the commands of its own entities.
+  let refresh_buttons = iter::once(SubDevice::Main)
+    .chain(sub_devices.iter().copied())
+    .collect::<BTreeSet<_>>()
+    .into_iter()
+    .map(|sub_device| {
+      let command_names = device_commands
+        .iter()
+        .filter(|&&(device_id, _)| device_id == sub_device.id())
+        .map(|&(_, command_name)| command_name)
+        .collect();
+      RefreshButton::new(sub_device, command_names, &mut next_key)
+    })
+    .collect::<Vec<_>>();
+
+  let (areas, devices) = sub_devices::device_infos(sub_devices, suggested_area.as_deref());
+
+  let (listener, addr) = listen_addresses
+    .iter()
+    .find_map(|&addr| match bind(addr) {
+      Ok(listener) => Some((listener, addr)),
+      Err(err) => {
+        log::warn!("Failed to listen on {addr}: {err}");
+        None
+      },
+    })
+    .expect("Failed to listen on any address");
+  log::info!("Listening on: {}", addr);
 
   let mac_address = get_mac_address().unwrap().unwrap_or_default();
   let encryption_key = env::var("ESPHOME_ENCRYPTION_KEY").unwrap_or_default();
 
+  let mdns = match mdns::advertise(&name, &friendly_name, mac_address, addr.port(), !encryption_key.is_empty()) {
+    Ok(mdns) => Some(mdns),
+    Err(err) => {
+      log::error!("Failed to advertise via mDNS: {err}");
+      None
+    },
+  };
+
   let main_server = async move {
     log::info!("ESPHome server started.");
 
     let commands = Arc::new(commands);
     let entity_map = Arc::new(entities);
+    let climates = Arc::new(climates);
+    let water_heater = Arc::new(water_heater);
+    let error_events = Arc::new(error_events);
+    let consumed_commands = Arc::new(consumed_commands);
+    let refresh_buttons = Arc::new(refresh_buttons);
 
     loop {
       log::info!("Waiting for connection.");
@@ -64,11 +189,21 @@ pub async fn start(
 
       let peer_addr = stream.peer_addr().unwrap();
       log::info!("Accepted request from {peer_addr}.");
-      let vcontrol_weak = vcontrol_weak.clone();
+      let bus = bus.clone();
       let commands = commands.clone();
-      let vcontrol_rx = vcontrol_rx.resubscribe();
+      let store = store.clone();
+      let refresher = refresher.clone();
       let entity_map = entity_map.clone();
+      let climates = climates.clone();
+      let water_heater = water_heater.clone();
+      let error_events = error_events.clone();
+      let subscriptions = subscriptions.clone();
+      let consumed_commands = consumed_commands.clone();
+      let refresh_buttons = refresh_buttons.clone();
       let encryption_key = encryption_key.clone();
+      let (name, friendly_name, manufacturer, model, suggested_area) =
+        (name.clone(), friendly_name.clone(), manufacturer.clone(), model.clone(), suggested_area.clone());
+      let (areas, devices) = (areas.clone(), devices.clone());
 
       tokio::task::spawn(async move {
         log::info!("Starting ESPHome API server to {peer_addr}.");
@@ -78,14 +213,16 @@ pub async fn start(
           .api_version_minor(42)
           .server_info("ESPHome Rust".into())
           // .esphome_version("2025.12.1".into()) // FIXME: Should be set by `esphome-native-api` automatically.
-          .name("vitoligno_300c".into())
-          .friendly_name("Vitoligno 300-C".into())
+          .name(name)
+          .friendly_name(friendly_name)
           .mac(mac_address.to_string())
           // .bluetooth_mac_address(bluetooth_mac_address.to_string())
           // .bluetooth_proxy_feature_flags(0b1111111)
-          .manufacturer("Viessmann".to_string())
-          .model("Vitoligno 300-C".to_string())
-          .suggested_area("Boiler Room".to_string())
+          .manufacturer(manufacturer)
+          .model(model)
+          .suggested_area_opt(suggested_area)
+          .areas(areas)
+          .devices(devices)
           .encryption_key(encryption_key.clone())
           .build();
 
@@ -95,6 +232,7 @@ pub async fn start(
         let entity_map = Arc::clone(&entity_map);
 
         let mut send_state_loop_task: Option<JoinHandle<()>> = None;
+        let mut send_log_loop_task: Option<JoinHandle<()>> = None;
 
         loop {
           let message = match rx.recv().await {
@@ -145,6 +283,59 @@ pub async fn start(
                 }
               }
 
+              if res.is_ok() {
+                for climate in climates.iter() {
+                  let entity = ProtoMessage::ListEntitiesClimateResponse(climate.entity());
+                  if let Err(err) = tx_clone.send(entity).await {
+                    res = Err(err);
+                    break;
+                  }
+                }
+              }
+
+              if res.is_ok()
+                && let Some(water_heater) = water_heater.as_ref()
+                && let Err(err) =
+                  tx_clone.send(ProtoMessage::ListEntitiesWaterHeaterResponse(water_heater.entity())).await
+              {
+                res = Err(err);
+              }
+
+              if res.is_ok()
+                && let Some(error_events) = error_events.as_ref()
+              {
+                let events = error_events.entities(SubDevice::Main.id()).map(ProtoMessage::ListEntitiesEventResponse);
+                let timestamps = error_events
+                  .timestamp_entities(SubDevice::Main.id())
+                  .map(ProtoMessage::ListEntitiesTextSensorResponse);
+                for entity in events.into_iter().chain(timestamps) {
+                  if let Err(err) = tx_clone.send(entity).await {
+                    res = Err(err);
+                    break;
+                  }
+                }
+              }
+
+              if res.is_ok() {
+                for refresh_button in refresh_buttons.iter() {
+                  if let Err(err) =
+                    tx_clone.send(ProtoMessage::ListEntitiesButtonResponse(refresh_button.entity())).await
+                  {
+                    res = Err(err);
+                    break;
+                  }
+                }
+              }
+
+              if res.is_ok() {
+                for service in services::services(&commands) {
+                  if let Err(err) = tx_clone.send(ProtoMessage::ListEntitiesServicesResponse(service)).await {
+                    res = Err(err);
+                    break;
+                  }
+                }
+              }
+
               if let Err(err) = res {
                 Err(err)
               } else {
@@ -152,25 +343,70 @@ pub async fn start(
               }
             },
             ProtoMessage::DateCommandRequest(request) => {
-              let Some(vcontrol) = vcontrol_weak.upgrade() else { break };
-              handle_date_command(request, &entity_map, vcontrol, &tx).await
+              if bus.is_closed() {
+                break;
+              }
+              handle_date_command(request, &entity_map, &bus, &tx).await
             },
             ProtoMessage::DateTimeCommandRequest(request) => {
-              let Some(vcontrol) = vcontrol_weak.upgrade() else { break };
-              handle_date_time_command(request, &entity_map, vcontrol, &tx).await
+              if bus.is_closed() {
+                break;
+              }
+              handle_date_time_command(request, &entity_map, &bus, time_zone, &tx).await
             },
             ProtoMessage::NumberCommandRequest(request) => {
-              let Some(vcontrol) = vcontrol_weak.upgrade() else { break };
-              handle_number_command(request, &entity_map, vcontrol, &tx).await
+              if bus.is_closed() {
+                break;
+              }
+              handle_number_command(request, &entity_map, &bus, &tx).await
             },
             ProtoMessage::SwitchCommandRequest(request) => {
-              let Some(vcontrol) = vcontrol_weak.upgrade() else { break };
-              handle_switch_command(request, &entity_map, vcontrol, &tx).await
+              if bus.is_closed() {
+                break;
+              }
+              handle_switch_command(request, &entity_map, &bus, &tx).await
+            },
+            ProtoMessage::TextCommandRequest(request) => {
+              if bus.is_closed() {
+                break;
+              }
+              handle_text_command(request, &entity_map, &bus, &tx).await
+            },
+            ProtoMessage::ClimateCommandRequest(request) => {
+              if bus.is_closed() {
+                break;
+              }
+              handle_climate_command(request, &climates, &bus, &tx).await
+            },
+            ProtoMessage::WaterHeaterCommandRequest(request) => {
+              if bus.is_closed() {
+                break;
+              }
+              handle_water_heater_command(request, &water_heater, &bus, &tx).await
+            },
+            ProtoMessage::ExecuteServiceRequest(request) => {
+              if bus.is_closed() {
+                break;
+              }
+              handle_execute_service(request, &bus, &refresher).await
+            },
+            ProtoMessage::ButtonCommandRequest(ButtonCommandRequest { key, device_id }) => {
+              if let Some(refresh_button) =
+                refresh_buttons.iter().find(|refresh_button| refresh_button.matches(device_id, key))
+              {
+                refresher.refresh(refresh_button.command_names());
+              } else {
+                log::warn!("Unknown button command: {key}");
+              }
+              Ok(())
             },
             ProtoMessage::SubscribeStatesRequest(SubscribeStatesRequest {}) => {
               let tx = tx.clone();
-              let vcontrol_rx = vcontrol_rx.resubscribe();
+              let updates = store.updates();
               let entity_map = Arc::clone(&entity_map);
+              let climates = Arc::clone(&climates);
+              let water_heater = Arc::clone(&water_heater);
+              let error_events = Arc::clone(&error_events);
               let commands = Arc::clone(&commands);
 
               if let Some(send_state_loop_task) = send_state_loop_task.take() {
@@ -178,14 +414,45 @@ pub async fn start(
                 send_state_loop_task.abort();
               }
 
-              let vcontrol_weak = vcontrol_weak.clone();
+              // Poll the consumed commands until the “send state” loop is stopped.
+              let subscription = subscriptions.subscribe(consumed_commands.iter().copied());
+
+              let device = bus.device();
               send_state_loop_task = Some(tokio::spawn(async move {
+                let _subscription = subscription;
                 log::info!("Starting “send state” loop.");
-                send_state_loop(tx, vcontrol_rx, vcontrol_weak, entity_map, commands).await;
+                send_state_loop(
+                  tx,
+                  updates,
+                  device,
+                  entity_map,
+                  climates,
+                  water_heater,
+                  error_events,
+                  commands,
+                  time_zone,
+                  max_state_age,
+                )
+                .await;
               }));
 
               Ok(())
             },
+            ProtoMessage::SubscribeLogsRequest(SubscribeLogsRequest { level, .. }) => {
+              let tx = tx.clone();
+              let max_level = LogLevel::try_from(level).unwrap_or(LogLevel::Info);
+              let log_rx = logger::subscribe(level_filter(max_level));
+
+              if let Some(send_log_loop_task) = send_log_loop_task.take() {
+                log::info!("Stopping previous “send log” loop.");
+                send_log_loop_task.abort();
+              }
+
+              log::info!("Starting “send log” loop with level {max_level:?}.");
+              send_log_loop_task = Some(tokio::spawn(send_log_loop(tx, log_rx, max_level)));
+
+              Ok(())
+            },
             request => {
               log::warn!("Unhandled request: {request:?}");
               Ok(())
@@ -202,6 +469,11 @@ pub async fn start(
           log::info!("Stopping “send state” loop.");
           send_state_loop_task.abort();
         }
+
+        if let Some(send_log_loop_task) = send_log_loop_task.take() {
+          log::info!("Stopping “send log” loop.");
+          send_log_loop_task.abort();
+        }
       });
     }
 
@@ -210,14 +482,22 @@ pub async fn start(
   };
 
   let main_server = async {
-    tokio::select! {
+    let res = tokio::select! {
       res = main_server => res,
       _ = server_stop_rx => {
         log::info!("ESPHome server stopped.");
 
         Ok(())
       },
+    };
+
+    if let Some(mdns) = mdns
+      && let Err(err) = mdns.shutdown()
+    {
+      log::error!("Failed to stop mDNS advertisement: {err}");
     }
+
+    res
   };
 
   let main_server = tokio::spawn(main_server);
@@ -0,0 +1,824 @@
+use esphome_native_api::proto::version_2026_1_5::EntityCategory;
+
+use crate::esphome_server::components::{Component, Detection};
+use crate::esphome_server::entity::{Entity, EntityType};
+use crate::esphome_server::sub_devices::SubDevice;
+
+/// Ecotronic configuration commands of the installed components.
+pub const COMPONENTS: Detection = Detection {
+  flags: &[
+    ("Ecotronic_HK1_Angeschlossen", Component::HeatingCircuit1),
+    ("Ecotronic_HK2_Angeschlossen", Component::HeatingCircuit2),
+    ("NRF_WW_vorhanden", Component::HotWater),
+  ],
+  system_scheme: &[],
+  probe_count: Some("Ecotronic_Anzahl_Saugsonden"),
+  commands: &[
+    ("Ecotronic_Heizung_Raumtemperatur_HK1", Component::HeatingCircuit1),
+    ("KF2_KonfiPartyzeitA1M1", Component::HeatingCircuit1),
+    ("Ecotronic_Heizung_Raumtemperatur_HK2", Component::HeatingCircuit2),
+    ("KF2_KonfiPartyzeitM2", Component::HeatingCircuit2),
+    ("Ecotronic_Umschalteinheit_Laufzeit_Sonde_1", Component::Probe(1)),
+    ("Ecotronic_Umschalteinheit_Laufzeit_Sonde_2", Component::Probe(2)),
+    ("Ecotronic_Umschalteinheit_Laufzeit_Sonde_3", Component::Probe(3)),
+    ("Ecotronic_Umschalteinheit_Laufzeit_Sonde_4", Component::Probe(4)),
+    ("Ecotronic_Umschalteinheit_Laufzeit_Sonde_5", Component::Probe(5)),
+    ("Ecotronic_Umschalteinheit_Laufzeit_Sonde_6", Component::Probe(6)),
+    ("Ecotronic_Umschalteinheit_Laufzeit_Sonde_7", Component::Probe(7)),
+    ("Ecotronic_Umschalteinheit_Laufzeit_Sonde_8", Component::Probe(8)),
+  ],
+};
+
+/// Entities for the Vitoligno 300-C with Ecotronic controller.
+pub const ENTITIES: &[(&str, Entity)] = &[
+  // Buffer
+  (
+    "Ecotronic_Kessel_Ein_Aus",
+    Entity { sub_device: SubDevice::Boiler, entity_name: "Boiler", entity_type: EntityType::Switch },
+  ),
+  (
+    "Ecotronic_Puffer_Betriebsart",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Operating Mode",
+      entity_type: EntityType::Select { category: EntityCategory::Config },
+    },
+  ),
+  (
+    "Ecotronic_Pufferladezustand",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Load State",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Puffertemperatur_Mittelwert",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Mean Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Puffersoll_Minimal",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Minimum Temperature",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Puffersoll_Maximal",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Maximum Temperature",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Puffertemperatur_Soll",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Desired Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Puffertemperatur_Ist",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Puffertemperatur_1",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Temperature 1",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Puffertemperatur_2",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Temperature 2",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Puffertemperatur_3",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Temperature 3",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Puffer_Niveau",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Niveau",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Puffer_Neigung",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Incline",
+      entity_type: EntityType::Number { step: 0.1 },
+    },
+  ),
+  // Hot Water
+  (
+    "Ecotronic_Bedien_WW_Solltemperatur",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Hot Water Desired Temperature",
+      entity_type: EntityType::Number { step: 0.1 },
+    },
+  ),
+  (
+    "Ecotronic_WW_Wunschtemperatur",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Hot Water Current Desired Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "NRF_TiefpassTemperaturwert_STS",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Hot Water Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_WW_Programm_Zustand",
+    Entity { sub_device: SubDevice::HotWater, entity_name: "Hot Water Mode", entity_type: EntityType::Switch },
+  ),
+  (
+    "Ecotronic_WW_Status",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Hot Water Status",
+      entity_type: EntityType::TextSensor { category: EntityCategory::None },
+    },
+  ),
+  (
+    "NRF_DigitalAusgang_SLP",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Load Pump",
+      entity_type: EntityType::BinarySensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "NRF_Schaltzeiten_M1_WW",
+    Entity { sub_device: SubDevice::HotWater, entity_name: "Hot Water Schedule", entity_type: EntityType::Schedule },
+  ),
+  (
+    "NRF_Schaltzeiten_M1_ZP",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Circulation Pump Schedule",
+      entity_type: EntityType::Schedule,
+    },
+  ),
+  // Heating Circuit 1
+  (
+    "Ecotronic_Betriebsart_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Operating Mode",
+      entity_type: EntityType::Select { category: EntityCategory::Config },
+    },
+  ),
+  (
+    "Ecotronic_Raumsoll_Normal_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Desired Room Temperature",
+      entity_type: EntityType::Number { step: 0.1 },
+    },
+  ),
+  (
+    "Ecotronic_Raumsoll_Reduziert_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Desired Reduced Room Temperature",
+      entity_type: EntityType::Number { step: 0.1 },
+    },
+  ),
+  (
+    "VT_SolltemperaturA1M1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Desired Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Heizung_Wunschtemperatur_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Desired Heating Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Vorlauftemperatur_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  // (
+  //   "Temperatur_2_M1", // Same as `Ecotronic_Vorlauftemperatur_HK1`.
+  //   Entity {
+  //     entity_name: "hc1_temperature_2",
+  //     entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+  //   },
+  // ),
+  (
+    "Ecotronic_HK_Ferienbetrieb_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Vacation Mode",
+      entity_type: EntityType::BinarySensor { category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_FerienBeginn_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Vacaction Mode Begin",
+      entity_type: EntityType::Date,
+    },
+  ),
+  (
+    "Ecotronic_FerienEnde_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Vacation Mode End",
+      entity_type: EntityType::Date,
+    },
+  ),
+  (
+    "Ecotronic_BedienPartybetriebM1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Desired Party Mode Temperature",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_BedienSparbetrieb_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Energy Saver Mode",
+      entity_type: EntityType::Switch,
+    },
+  ),
+  (
+    "Schaltzeiten_A1M1_HK",
+    Entity { sub_device: SubDevice::HeatingCircuit1, entity_name: "HC1 Schedule", entity_type: EntityType::Schedule },
+  ),
+  (
+    "Ecotronic_BedienNiveauM1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Niveau",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_BedienNeigung_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Incline",
+      entity_type: EntityType::Number { step: 0.1 },
+    },
+  ),
+  (
+    "Ecotronic_Pumpe_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Pump",
+      entity_type: EntityType::BinarySensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Mischerposition_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Mixer Position",
+      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Heizungstatus",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Heating Status",
+      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  // Heating Circuit 2
+  (
+    "Ecotronic_Betriebsart_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Operating Mode",
+      entity_type: EntityType::Select { category: EntityCategory::Config },
+    },
+  ),
+  (
+    "Ecotronic_Raumsoll_Normal_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Desired Room Temperature",
+      entity_type: EntityType::Number { step: 0.1 },
+    },
+  ),
+  (
+    "Ecotronic_Raumsoll_Reduziert_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Desired Reduced Room Temperature",
+      entity_type: EntityType::Number { step: 0.1 },
+    },
+  ),
+  (
+    "Ecotronic_Vorlauftemperatur_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  // (
+  //   "Temperatur_2_M2", // Same as `Ecotronic_Vorlauftemperatur_HK2`.
+  //   Entity { entity_name: "hc2_temperature_2", entity_type: EntityType::Sensor { accuracy_decimals: 1 },
+  //     category: EntityCategory::None },
+  // ),
+  (
+    "Ecotronic_Heizung_Wunschtemperatur_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Desired Heating Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "VT_SolltemperaturM2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Desired Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_HK_Ferienbetrieb_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Vacation Mode",
+      entity_type: EntityType::BinarySensor { category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_FerienBeginn_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Vacation Mode Begin",
+      entity_type: EntityType::Date,
+    },
+  ),
+  (
+    "Ecotronic_FerienEnde_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Vacation Mode End",
+      entity_type: EntityType::Date,
+    },
+  ),
+  (
+    "Ecotronic_BedienPartybetriebM2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Desired Party Mode Temperature",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_BedienSparbetrieb_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Energy Saver Mode",
+      entity_type: EntityType::Switch,
+    },
+  ),
+  (
+    "Schaltzeiten_M2_HK",
+    Entity { sub_device: SubDevice::HeatingCircuit2, entity_name: "HC2 Schedule", entity_type: EntityType::Schedule },
+  ),
+  (
+    "Ecotronic_BedienNiveauM2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Niveau",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_BedienNeigung_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Incline",
+      entity_type: EntityType::Number { step: 0.1 },
+    },
+  ),
+  (
+    "Ecotronic_Pumpe_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Pump",
+      entity_type: EntityType::BinarySensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Mischerposition_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Mixer Position",
+      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Heizungstatus_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Heating Status",
+      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  // Boiler
+  (
+    "Ecotronic_Kesseltype",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Type",
+      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Kesselstatus",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Status",
+      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "SC100_KesselIsttemperatur",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Abgastemperatur",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Exhaust Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "SC100_Lambdasonde",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Exhaust Rest O2",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "SC100_PositionPrimaerluftklappe",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Primary Flap Position",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "SC100_PositionSekundaerluftklappe",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Secondary Flap Position",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Kesselsolltemperatur",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Desired Temperature",
+      entity_type: EntityType::Number { step: 0.1 },
+    },
+  ),
+  (
+    "Ecotronic_Kessel_Rücklauf_Soll",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Desired Return Temperature",
+      entity_type: EntityType::Number { step: 0.1 },
+    },
+  ),
+  (
+    "Ecotronic_Kesselrücklauftemperatur",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Return Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Kesselstarts",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Starts",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Betriebsstunden_Volllast",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Operating Hours Full Load",
+      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Betriebsstunden_Teillast",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Operating Hours Partial Load",
+      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Betriebsstunden_Kessel",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Operating Hours",
+      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Betriebsstunden_Einschubschnecke",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Pellet Worm Drive Operating Hours",
+      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Betriebsminuten_Einschubschnecke",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Pellet Worm Drive Operating Minutes",
+      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
+    },
+  ),
+  // Ash
+  (
+    "Ecotronic_Füllstand_Entaschung",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Ash Level",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  // Pellets
+  (
+    "Ecotronic_Brennstofflager_Füllstand",
+    Entity {
+      sub_device: SubDevice::PelletStore,
+      entity_name: "Pellet Silo Level",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Brennstofflager_Minimalbegrenzung",
+    Entity {
+      sub_device: SubDevice::PelletStore,
+      entity_name: "Pellet Silo Minimum Level",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Brennstofflager_Maximalbegrenzung",
+    Entity {
+      sub_device: SubDevice::PelletStore,
+      entity_name: "Pellet Silo Maximum Level",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Füllstand_Pellet",
+    Entity {
+      sub_device: SubDevice::PelletStore,
+      entity_name: "Pellet Level",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Brennstoffverbrauch",
+    Entity {
+      sub_device: SubDevice::PelletStore,
+      entity_name: "Pellet Consumption per Hour",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "NRF_Brennstoffverbrauch_Bedien",
+    Entity {
+      sub_device: SubDevice::PelletStore,
+      entity_name: "Pellet Consumption",
+      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Pellet_Leerfahrzeit",
+    Entity {
+      sub_device: SubDevice::PelletStore,
+      entity_name: "Pellet Hopper Empty Time",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  // Outside Temperature
+  (
+    "NRF_TemperaturFehler_ATS",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Outside Temperature Status",
+      entity_type: EntityType::BinarySensor { category: EntityCategory::None },
+    },
+  ),
+  (
+    "NRF_TiefpassTemperaturwert_ATS",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Outside Temperature Lowpass",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Gemischte_AT",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Outside Temperature Mixed",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Ecotronic_Gemischte_AT",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Outside Temperature Mixed 2",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  // Changeover Unit
+  (
+    "Ecotronic_Umschalteinheit_Sonde",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Current Probe",
+      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Umschalteinheit_Sonde_Laufzeit",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Current Probe Runtime",
+      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_1",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Probe 1 Runtime",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_2",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Probe 2 Runtime",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_3",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Probe 3 Runtime",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_4",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Probe 4 Runtime",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_5",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Probe 5 Runtime",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_6",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Probe 6 Runtime",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_7",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Probe 7 Runtime",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_8",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Probe 8 Runtime",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Umschalteinheit_Laufzeit_Sonde_Soll",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Desired Probe Runtime",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "Ecotronic_Betriebsstunden_Saugmodul",
+    Entity {
+      sub_device: SubDevice::ChangeoverUnit,
+      entity_name: "Changeover Unit Operating Hours",
+      entity_type: EntityType::Sensor { accuracy_decimals: 3, category: EntityCategory::Diagnostic },
+    },
+  ),
+  // Errors
+  (
+    "ecnsysEventType~ErrorIndex",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Error",
+      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "ecnsysEventType~Error",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Error History",
+      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Ecotronic_Fehler_Quittierung",
+    Entity { sub_device: SubDevice::Main, entity_name: "Error Acknowledgement", entity_type: EntityType::Switch },
+  ),
+  (
+    "NRF_Uhrzeit",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "System Time",
+      entity_type: EntityType::DateTime { category: EntityCategory::Config },
+    },
+  ),
+];
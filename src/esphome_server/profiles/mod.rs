@@ -0,0 +1,37 @@
+use vcontrol::Device;
+
+use super::components::Detection;
+use super::entity::Entity;
+
+mod ecotronic;
+mod vitocal;
+mod vitodens;
+
+/// Entities and component detection for a device family.
+pub struct Profile {
+  pub entities: &'static [(&'static str, Entity)],
+  pub components: Detection,
+}
+
+const EMPTY: Profile = Profile { entities: &[], components: Detection::NONE };
+
+const ECOTRONIC: Profile = Profile { entities: ecotronic::ENTITIES, components: ecotronic::COMPONENTS };
+const VITODENS: Profile = Profile { entities: vitodens::ENTITIES, components: vitodens::COMPONENTS };
+const VITOCAL: Profile = Profile { entities: vitocal::ENTITIES, components: vitocal::COMPONENTS };
+
+/// Returns the profile for the given device, or an empty profile if the device is unknown.
+///
+/// Profiles cover a device family, so commands missing on some variants are skipped.
+pub fn profile(device: &Device) -> &'static Profile {
+  let name = device.name();
+
+  if name == "Ecotronic" {
+    &ECOTRONIC
+  } else if name.starts_with("VScot") || name.starts_with("VDens") {
+    &VITODENS
+  } else if name.starts_with("CU401B_") {
+    &VITOCAL
+  } else {
+    &EMPTY
+  }
+}
@@ -0,0 +1,184 @@
+use esphome_native_api::proto::version_2026_1_5::EntityCategory;
+
+use crate::esphome_server::components::{Component, Detection};
+use crate::esphome_server::entity::{Entity, EntityType};
+use crate::esphome_server::sub_devices::SubDevice;
+
+/// Vitocal equipment features of the installed components.
+pub const COMPONENTS: Detection = Detection {
+  flags: &[
+    ("WPR3_Ausstattungsmerkmal_HK1", Component::HeatingCircuit1),
+    ("WPR3_Ausstattungsmerkmal_HK2", Component::HeatingCircuit2),
+    ("WPR3_Ausstattungsmerkmal_WW_Speicher", Component::HotWater),
+    ("WPR3_Ausstattungsmerkmal_Pufferspeicher", Component::Buffer),
+  ],
+  system_scheme: &[],
+  probe_count: None,
+  commands: &[],
+};
+
+/// Entities for Vitocal heat pumps with Vitotronic 200 WO1 (CU401B) controllers.
+pub const ENTITIES: &[(&str, Entity)] = &[
+  // Heat Pump
+  (
+    "WPR3_Sekundaer_Vorlauftemp_1",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Secondary Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "WPR3_Sekundaer_Ruecklauftemp_1",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Secondary Return Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "WPR3_PRIMAER_VL",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Primary Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "WPR3_PRIMAER_RL",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Primary Return Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "WPR3_Anlagenvorlauf",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "System Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "WPR3_B423_Leistung_Verdichter",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Compressor Power",
+      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::None },
+    },
+  ),
+  (
+    "WPR3_Heizleistung_1",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Heating Power",
+      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::None },
+    },
+  ),
+  (
+    "WPR3_Leistungsaufnahme_1",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Power Consumption",
+      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::None },
+    },
+  ),
+  // Buffer
+  (
+    "WPR3_Pufferspeichertemperatur",
+    Entity {
+      sub_device: SubDevice::Buffer,
+      entity_name: "Buffer Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  // Hot Water
+  (
+    "WPR3_WW_Betriebsart",
+    Entity { sub_device: SubDevice::HotWater, entity_name: "Hot Water Mode", entity_type: EntityType::Switch },
+  ),
+  (
+    "WPR3_WW_Temperatur_Oben",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Hot Water Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "WPR3_B034_Guelt_BAMTL_Vorlaufsoll_WW",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Hot Water Desired Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
+    },
+  ),
+  // Heating Circuit 1
+  (
+    "WPR3_Betriebsart_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Current Operating Mode",
+      entity_type: EntityType::TextSensor { category: EntityCategory::None },
+    },
+  ),
+  (
+    "WPR3_Raumsolltemp_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Current Desired Room Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "WPR3_Vorlaufsolltemperatur_HK1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Desired Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
+    },
+  ),
+  // Heating Circuit 2
+  (
+    "WPR3_Betriebsart_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Current Operating Mode",
+      entity_type: EntityType::TextSensor { category: EntityCategory::None },
+    },
+  ),
+  (
+    "WPR3_Raumsolltemp_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Current Desired Room Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "WPR3_Vorlauftemp_HK2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  // General
+  (
+    "WPR3_Aussentemperatur",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Outside Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "WPR3_Sammelstoerung",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Fault",
+      entity_type: EntityType::BinarySensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+];
@@ -0,0 +1,187 @@
+use esphome_native_api::proto::version_2026_1_5::EntityCategory;
+
+use crate::esphome_server::components::Detection;
+use crate::esphome_server::entity::{Entity, EntityType};
+use crate::esphome_server::sub_devices::SubDevice;
+
+/// Vitodens system schemes, which differ between controller variants.
+pub const COMPONENTS: Detection = Detection {
+  flags: &[],
+  system_scheme: &[
+    "K00_KonfiAnlagenschemaV300_V333",
+    "K00_KonfiAnlagenschemaGWG_W",
+    "K00_KonfiAnlagenschemaGWG_Uhr",
+    "K00_KonfiAnlagenschemaGWG_Konstant",
+  ],
+  probe_count: None,
+  commands: &[],
+};
+
+/// Entities for Vitodens gas condensing boilers with Vitotronic 100/200/300 controllers.
+pub const ENTITIES: &[(&str, Entity)] = &[
+  // Boiler
+  (
+    "TiefpassTemperaturwert_KTS",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Kesselsoll_eff",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Desired Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "TiefpassTemperaturwert_AGTS",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Boiler Exhaust Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "GWG_Flamme",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Burner Flame",
+      entity_type: EntityType::BinarySensor { category: EntityCategory::None },
+    },
+  ),
+  (
+    "BetriebsstundenBrennerGWG",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Burner Operating Hours",
+      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "InternePumpeDrehzahl",
+    Entity {
+      sub_device: SubDevice::Boiler,
+      entity_name: "Internal Pump Speed",
+      entity_type: EntityType::Sensor { accuracy_decimals: 0, category: EntityCategory::Diagnostic },
+    },
+  ),
+  // Hot Water
+  (
+    "Bedien_WW_Solltemperatur",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Hot Water Desired Temperature",
+      entity_type: EntityType::Number { step: 1.0 },
+    },
+  ),
+  (
+    "WW_SolltemperaturAktuell",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Hot Water Current Desired Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "TiefpassTemperaturwertWW1",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Hot Water Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "Speicherladepumpe",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Hot Water Pump",
+      entity_type: EntityType::BinarySensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "Zirkulationspumpe",
+    Entity {
+      sub_device: SubDevice::HotWater,
+      entity_name: "Circulation Pump",
+      entity_type: EntityType::BinarySensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  // Heating Circuit 1
+  (
+    "HK_AktuelleBetriebsartA1M1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Current Operating Mode",
+      entity_type: EntityType::TextSensor { category: EntityCategory::None },
+    },
+  ),
+  (
+    "HK_RaumsolltemperaturaktuellA1M1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Current Desired Room Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "VT_SolltemperaturA1M1",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit1,
+      entity_name: "HC1 Desired Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::Diagnostic },
+    },
+  ),
+  // Heating Circuit 2
+  (
+    "HK_AktuelleBetriebsartM2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Current Operating Mode",
+      entity_type: EntityType::TextSensor { category: EntityCategory::None },
+    },
+  ),
+  (
+    "HK_RaumsolltemperaturaktuellM2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Current Desired Room Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "VorlauftemperaturM2",
+    Entity {
+      sub_device: SubDevice::HeatingCircuit2,
+      entity_name: "HC2 Flow Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  // General
+  (
+    "TiefpassTemperaturwert_ATS",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Outside Temperature",
+      entity_type: EntityType::Sensor { accuracy_decimals: 1, category: EntityCategory::None },
+    },
+  ),
+  (
+    "ecnsysEventType~ErrorIndex",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Error",
+      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+  (
+    "ecnsysEventType~Error",
+    Entity {
+      sub_device: SubDevice::Main,
+      entity_name: "Error History",
+      entity_type: EntityType::TextSensor { category: EntityCategory::Diagnostic },
+    },
+  ),
+];
@@ -0,0 +1,48 @@
+use esphome_native_api::proto::version_2026_1_5::{EntityCategory, ListEntitiesButtonResponse};
+
+use crate::esphome_server::sub_devices::SubDevice;
+
+/// Button which requests an immediate read of all commands consumed by the entities of a sub-device.
+#[derive(Debug, Clone)]
+pub struct RefreshButton {
+  pub key: u32,
+  sub_device: SubDevice,
+  command_names: Vec<&'static str>,
+}
+
+impl RefreshButton {
+  /// Creates the button for the given sub-device, allocating its key from `next_key`.
+  pub fn new(sub_device: SubDevice, command_names: Vec<&'static str>, next_key: &mut u32) -> Self {
+    let key = *next_key;
+    *next_key += 1;
+    Self { key, sub_device, command_names }
+  }
+
+  /// Returns whether this button has the given device ID and key.
+  pub fn matches(&self, device_id: u32, key: u32) -> bool {
+    self.sub_device.id() == device_id && self.key == key
+  }
+
+  /// Returns the commands refreshed by this button.
+  pub fn command_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+    self.command_names.iter().copied()
+  }
+
+  pub fn entity(&self) -> ListEntitiesButtonResponse {
+    let object_id = match self.sub_device {
+      SubDevice::Main => "refresh".into(),
+      sub_device => format!("{}_refresh", sub_device.name().to_lowercase().replace(' ', "_")),
+    };
+
+    ListEntitiesButtonResponse {
+      device_id: self.sub_device.id(),
+      object_id,
+      key: self.key,
+      name: "Refresh".into(),
+      icon: "mdi:refresh".into(),
+      disabled_by_default: false,
+      entity_category: EntityCategory::Config as i32,
+      device_class: "".into(),
+    }
+  }
+}
@@ -1,36 +1,38 @@
-use std::{collections::HashMap, sync::Arc};
+use std::collections::HashMap;
 
 use esphome_native_api::{
   parser::ProtoMessage,
-  proto::version_2025_12_1::{
-    DateCommandRequest, DateStateResponse, DateTimeCommandRequest, DateTimeStateResponse, NumberCommandRequest,
-    NumberStateResponse, SwitchCommandRequest, SwitchStateResponse,
+  proto::version_2026_1_5::{
+    ClimateCommandRequest, DateCommandRequest, DateStateResponse, DateTimeCommandRequest, DateTimeStateResponse,
+    ExecuteServiceRequest, NumberCommandRequest, NumberStateResponse, SwitchCommandRequest, SwitchStateResponse,
+    TextCommandRequest, TextStateResponse, WaterHeaterCommandRequest,
   },
 };
-use tokio::sync::{
-  Mutex,
-  mpsc::{Sender, error::SendError},
-};
-use vcontrol::{
-  VControl, Value,
-  types::{Date, DateTime},
-};
+use tokio::sync::mpsc::{Sender, error::SendError};
+use vcontrol::{Value, types::Date};
 
+use crate::bus::{Bus, Priority};
+use crate::command_poller::Refresher;
+use crate::esphome_server::climate::Climate;
 use crate::esphome_server::entities::MultiEntity;
+use crate::esphome_server::services::Service;
+use crate::esphome_server::water_heater::WaterHeater;
+use crate::schedule::{DaySchedule, Schedule};
+use crate::time_zone::TimeZone;
 
 pub async fn handle_date_command(
   request: DateCommandRequest,
   entity_map: &HashMap<&str, MultiEntity>,
-  vcontrol: Arc<Mutex<VControl>>,
+  bus: &Bus,
   tx: &Sender<ProtoMessage>,
 ) -> Result<(), SendError<ProtoMessage>> {
   let key = request.key;
-  let Some((command_name, _)) = entity_map.iter().find(|(_, e)| e.key() == key) else {
+  let Some((command_name, _)) = entity_map.iter().find(|(_, e)| e.matches(request.device_id, key)) else {
     log::warn!("Unknown date command: {key}");
     return Ok(());
   };
 
-  let mut vcontrol = vcontrol.lock().await;
+  let Ok(mut vcontrol) = bus.acquire(Priority::Write).await else { return Ok(()) };
 
   let date = Date::new(request.year as u16, request.month as u8, request.day as u8).unwrap();
   if let Err(err) = vcontrol.set(command_name, Value::Date(date)).await {
@@ -52,18 +54,23 @@ pub async fn handle_date_command(
 pub async fn handle_date_time_command(
   request: DateTimeCommandRequest,
   entity_map: &HashMap<&str, MultiEntity>,
-  vcontrol: Arc<Mutex<VControl>>,
+  bus: &Bus,
+  time_zone: TimeZone,
   tx: &Sender<ProtoMessage>,
 ) -> Result<(), SendError<ProtoMessage>> {
   let key = request.key;
-  let Some((command_name, _)) = entity_map.iter().find(|(_, e)| e.key() == key) else {
+  let Some((command_name, _)) = entity_map.iter().find(|(_, e)| e.matches(request.device_id, key)) else {
     log::warn!("Unknown date-time command: {key}");
     return Ok(());
   };
 
-  let mut vcontrol = vcontrol.lock().await;
+  let Some(date_time) = time_zone.date_time(request.epoch_seconds.into()) else {
+    log::error!("Invalid timestamp ({}) for {command_name}.", request.epoch_seconds);
+    return Ok(());
+  };
+
+  let Ok(mut vcontrol) = bus.acquire(Priority::Write).await else { return Ok(()) };
 
-  let date_time = DateTime::from_unix_timestamp(request.epoch_seconds);
   if let Err(err) = vcontrol.set(command_name, Value::DateTime(date_time)).await {
     log::error!("Failed to set value ({date_time}) for {command_name}: {err}");
     return Ok(());
@@ -81,16 +88,16 @@ pub async fn handle_date_time_command(
 pub async fn handle_number_command(
   request: NumberCommandRequest,
   entity_map: &HashMap<&str, MultiEntity>,
-  vcontrol: Arc<Mutex<VControl>>,
+  bus: &Bus,
   tx: &Sender<ProtoMessage>,
 ) -> Result<(), SendError<ProtoMessage>> {
   let key = request.key;
-  let Some((command_name, _)) = entity_map.iter().find(|(_, e)| e.key() == key) else {
+  let Some((command_name, _)) = entity_map.iter().find(|(_, e)| e.matches(request.device_id, key)) else {
     log::warn!("Unknown number command: {key}");
     return Ok(());
   };
 
-  let mut vcontrol = vcontrol.lock().await;
+  let Ok(mut vcontrol) = bus.acquire(Priority::Write).await else { return Ok(()) };
 
   let state = request.state;
   log::info!("Setting value for {command_name}: {state}");
@@ -111,16 +118,16 @@ pub async fn handle_number_command(
 pub async fn handle_switch_command(
   request: SwitchCommandRequest,
   entity_map: &HashMap<&str, MultiEntity>,
-  vcontrol: Arc<Mutex<VControl>>,
+  bus: &Bus,
   tx: &Sender<ProtoMessage>,
 ) -> Result<(), SendError<ProtoMessage>> {
   let key = request.key;
-  let Some((command_name, _)) = entity_map.iter().find(|(_, e)| e.key() == key) else {
+  let Some((command_name, _)) = entity_map.iter().find(|(_, e)| e.matches(request.device_id, key)) else {
     log::warn!("Unknown switch command: {key}");
     return Ok(());
   };
 
-  let mut vcontrol = vcontrol.lock().await;
+  let Ok(mut vcontrol) = bus.acquire(Priority::Write).await else { return Ok(()) };
 
   let state = request.state;
   log::info!("Setting value for {command_name}: {state}");
@@ -130,3 +137,150 @@ pub async fn handle_switch_command(
 
   tx.send(ProtoMessage::SwitchStateResponse(SwitchStateResponse { key, state, device_id: request.device_id })).await
 }
+
+pub async fn handle_text_command(
+  request: TextCommandRequest,
+  entity_map: &HashMap<&str, MultiEntity>,
+  bus: &Bus,
+  tx: &Sender<ProtoMessage>,
+) -> Result<(), SendError<ProtoMessage>> {
+  let key = request.key;
+  let Some((command_name, weekday)) = entity_map
+    .iter()
+    .filter(|(_, e)| e.device_id() == request.device_id)
+    .find_map(|(command_name, e)| Some((command_name, e.position(key)?)))
+  else {
+    log::warn!("Unknown text command: {key}");
+    return Ok(());
+  };
+
+  let day = match request.state.parse::<DaySchedule>() {
+    Ok(day) => day,
+    Err(err) => {
+      log::error!("Invalid schedule ({}) for {command_name}: {err}", request.state);
+      return Ok(());
+    },
+  };
+
+  let Ok(mut vcontrol) = bus.acquire(Priority::Write).await else { return Ok(()) };
+
+  let mut schedule = match vcontrol.get(command_name).await {
+    Ok(output) => match Schedule::try_from(&output.value) {
+      Ok(schedule) => schedule,
+      Err(err) => {
+        log::error!("Failed to decode current schedule for {command_name}: {err}");
+        return Ok(());
+      },
+    },
+    Err(err) => {
+      log::error!("Failed to get current schedule for {command_name}: {err}");
+      return Ok(());
+    },
+  };
+  schedule.set_day(weekday, day);
+
+  let value = match schedule.to_value() {
+    Ok(value) => value,
+    Err(err) => {
+      log::error!("Failed to encode schedule for {command_name}: {err}");
+      return Ok(());
+    },
+  };
+
+  let state = schedule.day(weekday).to_string();
+  log::info!("Setting schedule for {command_name} (day {weekday}): {state}");
+  if let Err(err) = vcontrol.set(command_name, value).await {
+    log::error!("Failed to set schedule ({state}) for {command_name}: {err}");
+    return Ok(());
+  }
+
+  tx.send(ProtoMessage::TextStateResponse(TextStateResponse {
+    key,
+    state,
+    missing_state: false,
+    device_id: request.device_id,
+  }))
+  .await
+}
+
+pub async fn handle_climate_command(
+  request: ClimateCommandRequest,
+  climates: &[Climate],
+  bus: &Bus,
+  tx: &Sender<ProtoMessage>,
+) -> Result<(), SendError<ProtoMessage>> {
+  let key = request.key;
+  let Some(climate) =
+    climates.iter().find(|climate| climate.sub_device().id() == request.device_id && climate.key == key)
+  else {
+    log::warn!("Unknown climate command: {key}");
+    return Ok(());
+  };
+
+  {
+    let Ok(mut vcontrol) = bus.acquire(Priority::Write).await else { return Ok(()) };
+
+    if let Err(err) = climate.apply(&mut vcontrol, &request).await {
+      log::error!("Failed to apply climate command {request:?}: {err}");
+    }
+  }
+
+  // Read back the resulting state without holding up other writes.
+  let Ok(mut vcontrol) = bus.acquire(Priority::Read).await else { return Ok(()) };
+  let values = climate.read_values(&mut vcontrol).await;
+  tx.send(ProtoMessage::ClimateStateResponse(climate.state(&values))).await
+}
+
+pub async fn handle_water_heater_command(
+  request: WaterHeaterCommandRequest,
+  water_heater: &Option<WaterHeater>,
+  bus: &Bus,
+  tx: &Sender<ProtoMessage>,
+) -> Result<(), SendError<ProtoMessage>> {
+  let key = request.key;
+  let Some(water_heater) = water_heater
+    .as_ref()
+    .filter(|water_heater| water_heater.sub_device().id() == request.device_id && water_heater.key == key)
+  else {
+    log::warn!("Unknown water heater command: {key}");
+    return Ok(());
+  };
+
+  {
+    let Ok(mut vcontrol) = bus.acquire(Priority::Write).await else { return Ok(()) };
+
+    if let Err(err) = water_heater.apply(&mut vcontrol, &request).await {
+      log::error!("Failed to apply water heater command {request:?}: {err}");
+    }
+  }
+
+  // Read back the resulting state without holding up other writes.
+  let Ok(mut vcontrol) = bus.acquire(Priority::Read).await else { return Ok(()) };
+  let values = water_heater.read_values(&mut vcontrol).await;
+  tx.send(ProtoMessage::WaterHeaterStateResponse(water_heater.state(&values))).await
+}
+
+pub async fn handle_execute_service(
+  request: ExecuteServiceRequest,
+  bus: &Bus,
+  refresher: &Refresher,
+) -> Result<(), SendError<ProtoMessage>> {
+  let key = request.key;
+  let Some(service) = Service::from_key(key) else {
+    log::warn!("Unknown service: {key}");
+    return Ok(());
+  };
+
+  if service == Service::Refresh {
+    refresher.refresh(request.args.iter().flat_map(|arg| &arg.string_array).map(String::as_str));
+    return Ok(());
+  }
+
+  let Ok(mut vcontrol) = bus.acquire(Priority::Write).await else { return Ok(()) };
+
+  if let Err(err) = service.execute(&mut vcontrol, &request.args).await {
+    log::error!("Failed to execute service {service:?}: {err}");
+  }
+
+  Ok(())
+}
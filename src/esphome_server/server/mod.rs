@@ -1,6 +1,9 @@
 mod send_entity_state;
 pub use send_entity_state::send_entity_state;
 
+mod send_log_loop;
+pub use send_log_loop::{level_filter, send_log_loop};
+
 mod send_state_loop;
 pub use send_state_loop::send_state_loop;
 
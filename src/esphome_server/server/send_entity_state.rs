@@ -1,13 +1,15 @@
 use std::collections::HashMap;
-use std::sync::Weak;
 
 use esphome_native_api::parser::ProtoMessage;
-use esphome_native_api::proto::version_2025_12_1::{
+use esphome_native_api::proto::version_2026_1_5::{
   BinarySensorStateResponse, DateStateResponse, DateTimeStateResponse, NumberStateResponse, SelectStateResponse,
-  SensorStateResponse, SwitchStateResponse, TextSensorStateResponse,
+  SensorStateResponse, SwitchStateResponse, TextSensorStateResponse, TextStateResponse,
 };
 use tokio::sync::mpsc::error::SendError;
-use vcontrol::{Command, VControl};
+use vcontrol::{Command, Device};
+
+use crate::esphome_server::entities::MultiEntity;
+use crate::time_zone::TimeZone;
 
 fn bool_state(value: &vcontrol::Value) -> Option<bool> {
   match value {
@@ -19,18 +21,20 @@ fn bool_state(value: &vcontrol::Value) -> Option<bool> {
 
 pub async fn send_entity_state(
   tx: tokio::sync::mpsc::Sender<ProtoMessage>,
-  vcontrol: Weak<tokio::sync::Mutex<VControl>>,
+  device: &'static Device,
   command_name: &'static str,
   commands: &HashMap<&'static str, &'static Command>,
   entity: &ProtoMessage,
   value: vcontrol::Value,
+  time_zone: TimeZone,
 ) -> Result<(), SendError<ProtoMessage>> {
-  let device_id = 0;
+  let device_id = MultiEntity::entity_device_id(entity);
 
   match entity {
     ProtoMessage::ListEntitiesBinarySensorResponse(res) => {
       let (missing_state, state) = match bool_state(&value) {
         Some(state) => (false, state),
+        None if value == vcontrol::Value::Empty => (true, false),
         None => {
           log::warn!("Unsupported value for binary sensor {command_name}: {value:?}");
           (true, false)
@@ -43,6 +47,11 @@ pub async fn send_entity_state(
     ProtoMessage::ListEntitiesSwitchResponse(res) => {
       let state = match bool_state(&value) {
         Some(state) => state,
+        // Switches cannot report a missing state, so they keep showing their last state.
+        None if value == vcontrol::Value::Empty => {
+          log::warn!("Cannot report missing state for switch {command_name}, keeping its last state.");
+          return Ok(());
+        },
         None => {
           log::error!("Unsupported value for switch {command_name}: {value:?}");
           return Ok(());
@@ -123,22 +132,21 @@ pub async fn send_entity_state(
       tx.send(ProtoMessage::DateStateResponse(message)).await?;
     },
     ProtoMessage::ListEntitiesDateTimeResponse(res) => {
-      let (missing_state, epoch_seconds) = match value {
-        vcontrol::Value::Empty => (true, 0),
-        vcontrol::Value::Error(error) => {
-          if let Some(time) = error.time() {
-            (false, time.unix_timestamp())
-          } else {
-            (true, 0)
-          }
-        },
-        vcontrol::Value::DateTime(datetime) => (false, datetime.unix_timestamp()),
+      let timestamp = match value {
+        vcontrol::Value::Empty => None,
+        vcontrol::Value::Error(error) => error.time().and_then(|time| time_zone.unix_timestamp(time)),
+        vcontrol::Value::DateTime(datetime) => time_zone.unix_timestamp(&datetime),
         value => {
           log::error!("Unsupported value for date-time: {value:?}");
           return Ok(());
         },
       };
 
+      let (missing_state, epoch_seconds) = match timestamp.and_then(|timestamp| u32::try_from(timestamp).ok()) {
+        Some(epoch_seconds) => (false, epoch_seconds),
+        None => (true, 0),
+      };
+
       let message = DateTimeStateResponse { device_id, key: res.key, missing_state, epoch_seconds };
       tx.send(ProtoMessage::DateTimeStateResponse(message)).await?;
     },
@@ -157,21 +165,30 @@ pub async fn send_entity_state(
             n.to_string()
           }
         },
+        vcontrol::Value::Date(date) => date.to_string(),
         vcontrol::Value::ByteArray(bytes) => {
           bytes.into_iter().map(|byte| format!("{byte:02X}")).collect::<Vec<String>>().join(", ")
         },
-        vcontrol::Value::Error(error) => {
-          let Some(vcontrol) = vcontrol.upgrade() else { return Ok(()) };
-          let vcontrol = vcontrol.lock().await;
-
-          error.to_str(vcontrol.device()).unwrap_or_default().to_owned()
-        },
+        vcontrol::Value::Error(error) => error.to_str(device).unwrap_or_default().to_owned(),
         state => format!("{state:?}"),
       };
 
       let message = TextSensorStateResponse { device_id, key: res.key, missing_state, state };
       tx.send(ProtoMessage::TextSensorStateResponse(message)).await?;
     },
+    ProtoMessage::ListEntitiesTextResponse(res) => {
+      let (missing_state, state) = match value {
+        vcontrol::Value::Empty => (true, "".to_owned()),
+        vcontrol::Value::String(s) => (false, s),
+        value => {
+          log::warn!("Unsupported value for text: {value:?}");
+          return Ok(());
+        },
+      };
+
+      let message = TextStateResponse { device_id, key: res.key, missing_state, state };
+      tx.send(ProtoMessage::TextStateResponse(message)).await?;
+    },
     ProtoMessage::ListEntitiesSelectResponse(res) => {
       let mut missing_state = false;
       let state = match value {
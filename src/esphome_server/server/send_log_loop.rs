@@ -0,0 +1,49 @@
+use esphome_native_api::parser::ProtoMessage;
+use esphome_native_api::proto::version_2026_1_5::{LogLevel, SubscribeLogsResponse};
+use log::{Level, LevelFilter};
+use tokio::sync::{broadcast, mpsc};
+
+use crate::logger::Subscription;
+
+fn log_level(level: Level) -> LogLevel {
+  match level {
+    Level::Error => LogLevel::Error,
+    Level::Warn => LogLevel::Warn,
+    Level::Info => LogLevel::Info,
+    Level::Debug => LogLevel::Debug,
+    Level::Trace => LogLevel::Verbose,
+  }
+}
+
+/// Returns the most verbose `log` level included in the given ESPHome log level.
+pub fn level_filter(level: LogLevel) -> LevelFilter {
+  match level {
+    LogLevel::None => LevelFilter::Off,
+    LogLevel::Error => LevelFilter::Error,
+    LogLevel::Warn => LevelFilter::Warn,
+    LogLevel::Info | LogLevel::Config => LevelFilter::Info,
+    LogLevel::Debug => LevelFilter::Debug,
+    LogLevel::Verbose | LogLevel::VeryVerbose => LevelFilter::Trace,
+  }
+}
+
+pub async fn send_log_loop(tx: mpsc::Sender<ProtoMessage>, mut log_rx: Subscription, max_level: LogLevel) {
+  loop {
+    let (level, message) = match log_rx.recv().await {
+      Ok(res) => res,
+      Err(broadcast::error::RecvError::Closed) => break,
+      // Logging here would only produce more records for this loop to skip.
+      Err(broadcast::error::RecvError::Lagged(_)) => continue,
+    };
+
+    let level = log_level(level);
+    if level > max_level {
+      continue;
+    }
+
+    let message = SubscribeLogsResponse { level: level as i32, message: message.into_bytes() };
+    if tx.send(ProtoMessage::SubscribeLogsResponse(message)).await.is_err() {
+      break;
+    }
+  }
+}
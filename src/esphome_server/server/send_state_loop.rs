@@ -1,63 +1,277 @@
-use std::collections::HashMap;
-use std::iter;
-use std::sync::{Arc, Weak};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use std::{iter, mem, slice};
+
+use chrono::{TimeDelta, Utc};
 
 use esphome_native_api::parser::ProtoMessage;
+use tokio::sync::mpsc;
 use tokio::sync::mpsc::error::SendError;
-use tokio::sync::{Mutex, broadcast, mpsc};
-use vcontrol::{Command, VControl, Value};
+use tokio::time;
+use vcontrol::{Command, Device, Value};
 
 use super::send_entity_state;
+use crate::command_poller::{CYCLE_END, Sample, Updates};
+use crate::esphome_server::climate::Climate;
 use crate::esphome_server::entities::MultiEntity;
+use crate::esphome_server::error_events::{ACTIVE_ERRORS_COMMAND, ErrorEvents};
+use crate::esphome_server::sub_devices::SubDevice;
+use crate::esphome_server::water_heater::WaterHeater;
+use crate::schedule::Schedule;
+use crate::time_zone::TimeZone;
+
+/// How often entities are checked for values older than the maximum state age.
+const STALENESS_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Values of the climate and water heater entities, whose states are sent once per poll cycle.
+#[derive(Default)]
+struct CycleStates {
+  values: HashMap<&'static str, Value>,
+  updated_climates: BTreeSet<u32>,
+  stale_climates: HashSet<u32>,
+  water_heater_updated: bool,
+  water_heater_stale: bool,
+}
+
+impl CycleStates {
+  /// Stores a value if it is part of a climate or water heater entity.
+  fn insert(
+    &mut self,
+    climates: &[Climate],
+    water_heater: Option<&WaterHeater>,
+    command_name: &'static str,
+    value: &Value,
+  ) {
+    for climate in climates.iter().filter(|climate| climate.contains(command_name)) {
+      self.values.insert(command_name, value.clone());
+      self.updated_climates.insert(climate.key);
+    }
+
+    if water_heater.is_some_and(|water_heater| water_heater.contains(command_name)) {
+      self.values.insert(command_name, value.clone());
+      self.water_heater_updated = true;
+    }
+  }
+
+  /// Sends the state of each updated climate and water heater entity whose values are complete.
+  ///
+  /// Entities with any stale value keep reporting a missing state.
+  async fn send(
+    &mut self,
+    tx: &mpsc::Sender<ProtoMessage>,
+    climates: &[Climate],
+    water_heater: Option<&WaterHeater>,
+    is_stale: impl Fn(&str) -> bool,
+  ) -> Result<(), SendError<ProtoMessage>> {
+    for climate in climates.iter().filter(|climate| self.updated_climates.contains(&climate.key)) {
+      if !climate.is_complete(&self.values) {
+        continue;
+      }
+
+      let state = if climate.readable_command_names().any(&is_stale) {
+        climate.missing_state(&self.values)
+      } else {
+        if self.stale_climates.remove(&climate.key) {
+          log::info!("Received values for climate {} again.", climate.sub_device().name());
+        }
+        climate.state(&self.values)
+      };
+      tx.send(ProtoMessage::ClimateStateResponse(state)).await?;
+    }
+    self.updated_climates.clear();
+
+    if let Some(water_heater) = water_heater
+      && mem::take(&mut self.water_heater_updated)
+      && water_heater.is_complete(&self.values)
+    {
+      let state = if water_heater.readable_command_names().any(&is_stale) {
+        water_heater.missing_state(&self.values)
+      } else {
+        if mem::take(&mut self.water_heater_stale) {
+          log::info!("Received values for water heater {} again.", water_heater.sub_device().name());
+        }
+        water_heater.state(&self.values)
+      };
+      tx.send(ProtoMessage::WaterHeaterStateResponse(state)).await?;
+    }
+
+    Ok(())
+  }
+}
 
+#[allow(clippy::too_many_arguments)]
 pub async fn send_state_loop(
   tx: mpsc::Sender<ProtoMessage>,
-  mut vcontrol_rx: broadcast::Receiver<(&'static str, Value)>,
-  vcontrol_weak: Weak<Mutex<VControl>>,
-  entity_map: Arc<HashMap<&str, MultiEntity>>,
+  mut updates: Updates,
+  device: &'static Device,
+  entity_map: Arc<HashMap<&'static str, MultiEntity>>,
+  climates: Arc<Vec<Climate>>,
+  water_heater: Arc<Option<WaterHeater>>,
+  error_events: Arc<Option<ErrorEvents>>,
   commands: Arc<HashMap<&'static str, &'static Command>>,
+  time_zone: TimeZone,
+  max_state_age: Duration,
 ) {
+  let max_state_age = TimeDelta::from_std(max_state_age).unwrap_or(TimeDelta::MAX);
+  let mut staleness_check = time::interval(STALENESS_CHECK_INTERVAL);
+  let started_at = Utc::now();
+  let mut stale = HashSet::new();
+  let mut cycle_states = CycleStates::default();
+  let mut current_cycle = None;
+
   'outer: loop {
-    let (command_name, value) = match vcontrol_rx.recv().await {
-      Ok(res) => res,
-      Err(broadcast::error::RecvError::Closed) => break,
-      Err(broadcast::error::RecvError::Lagged(n)) => {
-        log::warn!("Receiver lagged, {n} messages skipped.");
+    let res = tokio::select! {
+      res = updates.changed() => res,
+      _ = staleness_check.tick() => {
+        // Read times are kept in the store, so that reconnecting does not reset them. Commands which were never
+        // read become stale as well, e.g. if they are quarantined.
+        let now = Utc::now();
+        let is_stale = |command_name| now - updates.read_at(command_name) > max_state_age;
+
+        // Entities disabled by default are not polled, so their values are expected to be outdated.
+        for (&command_name, entity) in entity_map.iter().filter(|(_, entity)| !entity.is_disabled_by_default()) {
+          if !is_stale(command_name) || !stale.insert(command_name) {
+            continue;
+          }
+
+          let read_at = updates.read_at(command_name);
+          log::warn!("No value for command {command_name} since {read_at}, reporting missing state.");
+
+          let entities = match entity {
+            MultiEntity::Single(entity) => slice::from_ref(entity.as_ref()),
+            MultiEntity::Multiple(entities) => entities.as_slice(),
+          };
+          for entity in entities {
+            if let Err(SendError(message)) =
+              send_entity_state(tx.clone(), device, command_name, &commands, entity, Value::Empty, time_zone).await
+            {
+              log::error!("Failed to send message for command '{command_name}': {message:?}");
+              break 'outer;
+            }
+          }
+        }
+
+        for climate in climates.iter() {
+          if !climate.readable_command_names().any(is_stale) || !cycle_states.stale_climates.insert(climate.key) {
+            continue;
+          }
+
+          log::warn!("Outdated values for climate {}, reporting unknown temperatures.", climate.sub_device().name());
+
+          if let Err(SendError(message)) =
+            tx.send(ProtoMessage::ClimateStateResponse(climate.missing_state(&cycle_states.values))).await
+          {
+            log::error!("Failed to send message for climate {}: {message:?}", climate.sub_device().name());
+            break 'outer;
+          }
+        }
+
+        if let Some(water_heater) = water_heater.as_ref()
+          && water_heater.readable_command_names().any(is_stale)
+          && !mem::replace(&mut cycle_states.water_heater_stale, true)
+        {
+          let name = water_heater.sub_device().name();
+          log::warn!("Outdated values for water heater {name}, reporting unknown temperatures.");
+
+          let state = water_heater.missing_state(&cycle_states.values);
+          if let Err(SendError(message)) = tx.send(ProtoMessage::WaterHeaterStateResponse(state)).await {
+            log::error!("Failed to send message for water heater {name}: {message:?}");
+            break 'outer;
+          }
+        }
+
         continue;
       },
     };
 
-    let Some(entity) = entity_map.get(command_name) else {
-      // log::debug!("No entity for command {command_name}: {value:?}");
-      continue;
-    };
+    let Ok(samples) = res else { break };
+    let now = Utc::now();
+    let is_stale = |command_name: &str| now - updates.read_at(command_name) > max_state_age;
+
+    for Sample { command_name, value, read_at, cycle, range, previous } in samples {
+      log::trace!("Received {command_name} read at {read_at} in cycle {cycle} from {range:#06X?}.");
+
+      if stale.remove(command_name) {
+        log::info!("Received value for command {command_name} again.");
+      }
 
-    match entity {
-      MultiEntity::Single(entity) => {
-        match send_entity_state(tx.clone(), vcontrol_weak.clone(), command_name, &commands, entity, value).await {
-          Ok(()) => continue,
-          Err(SendError(message)) => {
+      // Climate and water heater states are sent once per poll cycle, so that they are based on values from the
+      // same cycle. Normally, this happens at the end of a cycle, unless the end was skipped, e.g. when the cycle was
+      // aborted.
+      if current_cycle.replace(cycle).is_some_and(|current_cycle| current_cycle != cycle)
+        && let Err(SendError(message)) =
+          cycle_states.send(&tx, &climates, water_heater.as_ref().as_ref(), is_stale).await
+      {
+        log::error!("Failed to send message for command '{command_name}': {message:?}");
+        break 'outer;
+      }
+
+      cycle_states.insert(&climates, water_heater.as_ref().as_ref(), command_name, &value);
+
+      if command_name == CYCLE_END
+        && let Err(SendError(message)) =
+          cycle_states.send(&tx, &climates, water_heater.as_ref().as_ref(), is_stale).await
+      {
+        log::error!("Failed to send message for command '{command_name}': {message:?}");
+        break 'outer;
+      }
+
+      // Changes read before subscribing were already reported to the clients connected at that time.
+      if command_name == ACTIVE_ERRORS_COMMAND
+        && read_at >= started_at
+        && let Some(error_events) = error_events.as_ref()
+      {
+        for message in error_events.update(SubDevice::Main.id(), previous.as_ref(), &value, read_at) {
+          if let Err(SendError(message)) = tx.send(message).await {
             log::error!("Failed to send message for command '{command_name}': {message:?}");
             break 'outer;
-          },
+          }
         }
-      },
-      MultiEntity::Multiple(entities) => {
-        let vcontrol::Value::Array(values) = value else {
-          log::warn!("Invalid value for command {command_name}: {value:?}");
-          continue;
-        };
-
-        for (entity, value) in entities.iter().zip(values.into_iter().flat_map(|v| iter::repeat_n(v, 2))) {
-          match send_entity_state(tx.clone(), vcontrol_weak.clone(), command_name, &commands, entity, value).await {
+      }
+
+      let Some(entity) = entity_map.get(command_name) else {
+        // log::debug!("No entity for command {command_name}: {value:?}");
+        continue;
+      };
+
+      match entity {
+        MultiEntity::Single(entity) => {
+          match send_entity_state(tx.clone(), device, command_name, &commands, entity, value, time_zone).await {
             Ok(()) => continue,
             Err(SendError(message)) => {
               log::error!("Failed to send message for command '{command_name}': {message:?}");
               break 'outer;
             },
           }
-        }
-      },
+        },
+        MultiEntity::Multiple(entities) => {
+          let values = match value {
+            vcontrol::Value::Array(values) => values.into_iter().flat_map(|v| iter::repeat_n(v, 2)).collect::<Vec<_>>(),
+            vcontrol::Value::CircuitTimes(_) => match Schedule::try_from(&value) {
+              Ok(schedule) => schedule.days().map(|day| Value::String(day.to_string())).collect(),
+              Err(err) => {
+                log::warn!("Invalid schedule for command {command_name}: {err}");
+                continue;
+              },
+            },
+            value => {
+              log::warn!("Invalid value for command {command_name}: {value:?}");
+              continue;
+            },
+          };
+
+          for (entity, value) in entities.iter().zip(values) {
+            match send_entity_state(tx.clone(), device, command_name, &commands, entity, value, time_zone).await {
+              Ok(()) => continue,
+              Err(SendError(message)) => {
+                log::error!("Failed to send message for command '{command_name}': {message:?}");
+                break 'outer;
+              },
+            }
+          }
+        },
+      }
     }
   }
 }
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use esphome_native_api::proto::version_2026_1_5::{
+  ExecuteServiceArgument, ListEntitiesServicesArgument, ListEntitiesServicesResponse, ServiceArgType,
+  SupportsResponseType,
+};
+use vcontrol::{Command, VControl, Value};
+
+use crate::{party_mode, transaction, vacation};
+
+/// User-defined services which can be called from Home Assistant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Service {
+  SetVacation,
+  CancelVacation,
+  SetPartyMode,
+  WriteCommand,
+  Refresh,
+}
+
+impl Service {
+  const ALL: &[Self] =
+    &[Self::SetVacation, Self::CancelVacation, Self::SetPartyMode, Self::WriteCommand, Self::Refresh];
+
+  fn name(self) -> &'static str {
+    match self {
+      Self::SetVacation => "set_vacation",
+      Self::CancelVacation => "cancel_vacation",
+      Self::SetPartyMode => "set_party_mode",
+      Self::WriteCommand => "write_command",
+      Self::Refresh => "refresh",
+    }
+  }
+
+  fn args(self) -> &'static [(&'static str, ServiceArgType)] {
+    match self {
+      Self::SetVacation => {
+        &[("circuit", ServiceArgType::Int), ("begin", ServiceArgType::String), ("end", ServiceArgType::String)]
+      },
+      Self::CancelVacation => &[("circuit", ServiceArgType::Int)],
+      Self::SetPartyMode => &[("circuit", ServiceArgType::Int), ("hours", ServiceArgType::Int)],
+      Self::WriteCommand => &[("command", ServiceArgType::String), ("value", ServiceArgType::String)],
+      Self::Refresh => &[("commands", ServiceArgType::StringArray)],
+    }
+  }
+
+  fn is_supported(self, commands: &HashMap<&str, &Command>) -> bool {
+    match self {
+      Self::SetVacation | Self::CancelVacation => vacation::is_supported(commands),
+      Self::SetPartyMode => party_mode::is_supported(commands),
+      Self::WriteCommand | Self::Refresh => true,
+    }
+  }
+
+  pub fn key(self) -> u32 {
+    Self::ALL.iter().position(|&service| service == self).unwrap() as u32
+  }
+
+  pub fn from_key(key: u32) -> Option<Self> {
+    Self::ALL.get(key as usize).copied()
+  }
+
+  pub async fn execute(self, vcontrol: &mut VControl, args: &[ExecuteServiceArgument]) -> Result<(), String> {
+    if args.len() != self.args().len() {
+      return Err(format!("expected {} arguments, got {}", self.args().len(), args.len()));
+    }
+
+    match self {
+      Self::SetVacation => {
+        let begin = vacation::parse_date(&args[1].string).map_err(|err| err.to_string())?;
+        let end = vacation::parse_date(&args[2].string).map_err(|err| err.to_string())?;
+        vacation::set_vacation(vcontrol, args[0].int, begin, end).await.map_err(|err| err.to_string())
+      },
+      Self::CancelVacation => vacation::cancel_vacation(vcontrol, args[0].int).await.map_err(|err| err.to_string()),
+      Self::SetPartyMode => {
+        party_mode::set_party_mode(vcontrol, args[0].int, args[1].int).await.map_err(|err| err.to_string())
+      },
+      Self::WriteCommand => {
+        let command_name = args[0].string.as_str();
+        let command = transaction::writable_command(vcontrol, command_name).map_err(|err| err.to_string())?;
+
+        let value = parse_value(command, &args[1].string);
+        log::info!("Setting value for {command_name}: {value:?}");
+        vcontrol.set(command_name, value).await.map_err(|err| err.to_string())
+      },
+      // Handled by the poller, since it does not write anything.
+      Self::Refresh => Err("refresh cannot be executed directly".into()),
+    }
+  }
+}
+
+/// Parses a value given as JSON, falling back to a string, in the same way as the `vcontrol` CLI.
+/// Strings matching a mapped value of the command are converted to the corresponding number.
+fn parse_value(command: &Command, value: &str) -> Value {
+  let value = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_owned()));
+
+  if let (Some(mapping), Value::String(s)) = (command.mapping(), &value)
+    && let Some((&n, _)) = mapping.entries().find(|(_, text)| *text == s)
+  {
+    return Value::Int(n.into());
+  }
+
+  value
+}
+
+/// Returns the services supported by the device.
+pub fn services(commands: &HashMap<&str, &Command>) -> Vec<ListEntitiesServicesResponse> {
+  Service::ALL
+    .iter()
+    .filter(|service| service.is_supported(commands))
+    .map(|&service| ListEntitiesServicesResponse {
+      name: service.name().to_owned(),
+      key: service.key(),
+      args: service
+        .args()
+        .iter()
+        .map(|&(name, r#type)| ListEntitiesServicesArgument { name: name.to_owned(), r#type: r#type as i32 })
+        .collect(),
+      supports_response: SupportsResponseType::SupportsResponseNone as i32,
+    })
+    .collect()
+}
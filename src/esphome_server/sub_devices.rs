@@ -0,0 +1,90 @@
+use std::collections::BTreeSet;
+
+use esphome_native_api::proto::version_2026_1_5::{AreaInfo, DeviceInfo};
+
+/// Area of the suggested area from the configuration, i.e. where the heating system is installed.
+const INSTALLATION_AREA_ID: u32 = 1;
+/// Area of the pellet store, which is usually a separate room.
+const PELLET_STORE_AREA_ID: u32 = 2;
+
+/// A part of the heating system which is shown as a separate device in Home Assistant.
+///
+/// Entities of [`SubDevice::Main`] belong to the device itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SubDevice {
+  Main,
+  Boiler,
+  Buffer,
+  HotWater,
+  HeatingCircuit1,
+  HeatingCircuit2,
+  PelletStore,
+  ChangeoverUnit,
+}
+
+impl SubDevice {
+  /// Returns the device ID. These must stay stable, since Home Assistant identifies devices by them.
+  pub const fn id(self) -> u32 {
+    match self {
+      Self::Main => 0,
+      Self::Boiler => 1,
+      Self::Buffer => 2,
+      Self::HotWater => 3,
+      Self::HeatingCircuit1 => 4,
+      Self::HeatingCircuit2 => 5,
+      Self::PelletStore => 6,
+      Self::ChangeoverUnit => 7,
+    }
+  }
+
+  pub const fn name(self) -> &'static str {
+    match self {
+      Self::Main => "",
+      Self::Boiler => "Boiler",
+      Self::Buffer => "Buffer",
+      Self::HotWater => "Hot Water",
+      Self::HeatingCircuit1 => "Heating Circuit 1",
+      Self::HeatingCircuit2 => "Heating Circuit 2",
+      Self::PelletStore => "Pellet Store",
+      Self::ChangeoverUnit => "Changeover Unit",
+    }
+  }
+
+  const fn area_id(self) -> u32 {
+    match self {
+      Self::PelletStore => PELLET_STORE_AREA_ID,
+      _ => INSTALLATION_AREA_ID,
+    }
+  }
+}
+
+/// Returns the areas and device infos to declare for the given sub-devices.
+///
+/// Sub-devices are placed in the suggested area, except for the pellet store which gets its own area. Without a
+/// suggested area, these sub-devices are not assigned to any area.
+pub fn device_infos(
+  sub_devices: impl IntoIterator<Item = SubDevice>,
+  suggested_area: Option<&str>,
+) -> (Vec<AreaInfo>, Vec<DeviceInfo>) {
+  let sub_devices =
+    sub_devices.into_iter().filter(|&sub_device| sub_device != SubDevice::Main).collect::<BTreeSet<_>>();
+
+  let mut areas = vec![];
+  if let Some(suggested_area) = suggested_area {
+    areas.push(AreaInfo { area_id: INSTALLATION_AREA_ID, name: suggested_area.to_owned() });
+  }
+  if sub_devices.contains(&SubDevice::PelletStore) {
+    areas.push(AreaInfo { area_id: PELLET_STORE_AREA_ID, name: SubDevice::PelletStore.name().to_owned() });
+  }
+
+  let devices = sub_devices
+    .into_iter()
+    .map(|sub_device| {
+      let area_id = sub_device.area_id();
+      let area_id = if areas.iter().any(|area| area.area_id == area_id) { area_id } else { 0 };
+      DeviceInfo { device_id: sub_device.id(), name: sub_device.name().to_owned(), area_id }
+    })
+    .collect();
+
+  (areas, devices)
+}
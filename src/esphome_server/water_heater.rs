@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use esphome_native_api::proto::version_2026_1_5::{
+  EntityCategory, ListEntitiesWaterHeaterResponse, WaterHeaterCommandHasField, WaterHeaterCommandRequest,
+  WaterHeaterMode, WaterHeaterStateResponse,
+};
+use vcontrol::{Command, DataType, VControl, Value};
+
+use crate::esphome_server::sub_devices::SubDevice;
+
+const SUPPORTS_CURRENT_TEMPERATURE: u32 = 1 << 0;
+const SUPPORTS_TARGET_TEMPERATURE: u32 = 1 << 1;
+const SUPPORTS_OPERATION_MODE: u32 = 1 << 2;
+
+/// Commands making up the water heater entity of the hot water circuit.
+///
+/// Apart from the mode and the target temperature, commands not supported by the device are ignored.
+struct HotWater {
+  sub_device: SubDevice,
+  /// Off (0) or on (1).
+  mode: &'static str,
+  target_temperature: &'static str,
+  current_temperature: &'static str,
+}
+
+const HOT_WATER: HotWater = HotWater {
+  sub_device: SubDevice::HotWater,
+  mode: "Ecotronic_WW_Programm_Zustand",
+  target_temperature: "Ecotronic_Bedien_WW_Solltemperatur",
+  current_temperature: "NRF_TiefpassTemperaturwert_STS",
+};
+
+/// A water heater entity controlling the hot water circuit.
+///
+/// The controller only distinguishes between off and on, so the on state is mapped to
+/// [`WaterHeaterMode::Performance`], which is the closest match for heating with the boiler.
+pub struct WaterHeater {
+  pub key: u32,
+  hot_water: &'static HotWater,
+  commands: HashMap<&'static str, &'static Command>,
+}
+
+impl WaterHeater {
+  fn is_readable(&self, command_name: &str) -> bool {
+    self.commands.get(command_name).is_some_and(|command| command.access_mode().is_read())
+  }
+
+  /// Returns whether values for all readable commands of this water heater entity are known.
+  pub fn is_complete(&self, values: &HashMap<&str, Value>) -> bool {
+    self
+      .commands
+      .iter()
+      .all(|(command_name, command)| !command.access_mode().is_read() || values.contains_key(command_name))
+  }
+
+  /// Returns whether the given command is part of this water heater entity.
+  pub fn contains(&self, command_name: &str) -> bool {
+    self.commands.contains_key(command_name)
+  }
+
+  /// Returns the sub-device of the hot water circuit.
+  pub fn sub_device(&self) -> SubDevice {
+    self.hot_water.sub_device
+  }
+
+  /// Returns all commands of this water heater entity.
+  pub fn command_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+    self.commands.keys().copied()
+  }
+
+  /// Returns all readable commands of this water heater entity.
+  pub fn readable_command_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+    self.commands.iter().filter(|(_, command)| command.access_mode().is_read()).map(|(&command_name, _)| command_name)
+  }
+
+  pub fn entity(&self) -> ListEntitiesWaterHeaterResponse {
+    let target_temperature = self.commands[self.hot_water.target_temperature];
+
+    let supports_current_temperature = self.is_readable(self.hot_water.current_temperature);
+    let step = if target_temperature.data_type() == DataType::Int { 1.0 } else { 0.5 };
+
+    ListEntitiesWaterHeaterResponse {
+      device_id: self.hot_water.sub_device.id(),
+      object_id: "hot_water".into(),
+      key: self.key,
+      name: "Hot Water".into(),
+      icon: "".into(),
+      disabled_by_default: false,
+      entity_category: EntityCategory::None as i32,
+      min_temperature: target_temperature.lower_bound().unwrap_or(10.0) as f32,
+      max_temperature: target_temperature.upper_bound().unwrap_or(70.0) as f32,
+      target_temperature_step: step,
+      supported_modes: vec![WaterHeaterMode::Off as i32, WaterHeaterMode::Performance as i32],
+      supported_features: if supports_current_temperature { SUPPORTS_CURRENT_TEMPERATURE } else { 0 }
+        | SUPPORTS_TARGET_TEMPERATURE
+        | SUPPORTS_OPERATION_MODE,
+    }
+  }
+
+  pub fn state(&self, values: &HashMap<&str, Value>) -> WaterHeaterStateResponse {
+    let hot_water = self.hot_water;
+
+    let temperature = |command_name| match values.get(command_name) {
+      Some(Value::Int(n)) => *n as f32,
+      Some(Value::Double(n)) => *n as f32,
+      _ => f32::NAN,
+    };
+
+    let mode = match values.get(hot_water.mode) {
+      Some(Value::Int(0)) => WaterHeaterMode::Off,
+      _ => WaterHeaterMode::Performance,
+    };
+
+    WaterHeaterStateResponse {
+      device_id: hot_water.sub_device.id(),
+      key: self.key,
+      current_temperature: temperature(hot_water.current_temperature),
+      target_temperature: temperature(hot_water.target_temperature),
+      mode: mode as i32,
+      state: 0,
+      target_temperature_low: 0.0,
+      target_temperature_high: 0.0,
+    }
+  }
+
+  /// Returns the state with unknown temperatures, since water heater entities cannot report a missing state.
+  pub fn missing_state(&self, values: &HashMap<&str, Value>) -> WaterHeaterStateResponse {
+    WaterHeaterStateResponse { current_temperature: f32::NAN, target_temperature: f32::NAN, ..self.state(values) }
+  }
+
+  /// Reads the current values of all readable commands of this water heater entity.
+  pub async fn read_values(&self, vcontrol: &mut VControl) -> HashMap<&'static str, Value> {
+    let mut values = HashMap::new();
+
+    for command_name in self.readable_command_names() {
+      match vcontrol.get(command_name).await {
+        Ok(output) => {
+          values.insert(command_name, output.value);
+        },
+        Err(err) => log::error!("Failed to get value for {command_name}: {err}"),
+      }
+    }
+
+    values
+  }
+
+  /// Applies the changes requested by a water heater command.
+  pub async fn apply(
+    &self,
+    vcontrol: &mut VControl,
+    request: &WaterHeaterCommandRequest,
+  ) -> Result<(), vcontrol::Error> {
+    let hot_water = self.hot_water;
+    let has_field = |field: WaterHeaterCommandHasField| request.has_fields & field as u32 != 0;
+
+    if has_field(WaterHeaterCommandHasField::WaterHeaterCommandHasMode) {
+      let mode = match WaterHeaterMode::try_from(request.mode) {
+        Ok(WaterHeaterMode::Off) => 0,
+        Ok(WaterHeaterMode::Performance) => 1,
+        mode => {
+          log::warn!("Unsupported mode for hot water: {mode:?}");
+          return Ok(());
+        },
+      };
+
+      set(vcontrol, hot_water.mode, Value::Int(mode)).await?;
+    }
+
+    if has_field(WaterHeaterCommandHasField::WaterHeaterCommandHasTargetTemperature) {
+      let target_temperature = f64::from(request.target_temperature);
+      let value = if self.commands[hot_water.target_temperature].data_type() == DataType::Int {
+        Value::Int(target_temperature.round() as i64)
+      } else {
+        Value::Double(target_temperature)
+      };
+
+      set(vcontrol, hot_water.target_temperature, value).await?;
+    }
+
+    Ok(())
+  }
+}
+
+async fn set(vcontrol: &mut VControl, command_name: &str, value: Value) -> Result<(), vcontrol::Error> {
+  log::info!("Setting value for {command_name}: {value:?}");
+  vcontrol.set(command_name, value).await
+}
+
+/// Returns a water heater entity if the device supports the hot water circuit, allocating its key from `next_key`.
+pub fn water_heater(commands: &HashMap<&'static str, &'static Command>, next_key: &mut u32) -> Option<WaterHeater> {
+  let hot_water = &HOT_WATER;
+
+  let is_writable = |command_name| commands.get(command_name).is_some_and(|command| command.access_mode().is_write());
+  if !is_writable(hot_water.mode) || !is_writable(hot_water.target_temperature) {
+    return None;
+  }
+
+  let hot_water_commands = [hot_water.mode, hot_water.target_temperature, hot_water.current_temperature]
+    .into_iter()
+    .filter_map(|command_name| commands.get(command_name).map(|&command| (command_name, command)))
+    .collect();
+
+  let water_heater = WaterHeater { key: *next_key, hot_water, commands: hot_water_commands };
+  *next_key += 1;
+
+  Some(water_heater)
+}
@@ -0,0 +1,117 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use tokio::sync::broadcast;
+
+/// Number of log records buffered per subscriber before the oldest ones are dropped.
+const CAPACITY: usize = 256;
+
+/// Log records from this target are not forwarded, since forwarding them would log even more records.
+const EXCLUDED_TARGET: &str = "esphome_native_api";
+
+static RECORDS: OnceLock<broadcast::Sender<(Level, String)>> = OnceLock::new();
+
+/// Maximum level configured by `RUST_LOG`.
+static STDERR_LEVEL: OnceLock<LevelFilter> = OnceLock::new();
+
+/// Levels requested by the current subscribers.
+static SUBSCRIBER_LEVELS: Mutex<Vec<LevelFilter>> = Mutex::new(Vec::new());
+
+/// Highest level in `SUBSCRIBER_LEVELS`, cached to avoid locking for every record.
+static SUBSCRIBER_LEVEL: AtomicUsize = AtomicUsize::new(LevelFilter::Off as usize);
+
+/// Logs to `stderr` using `env_logger` and additionally forwards records to subscribers.
+///
+/// `RUST_LOG` only filters the records logged to `stderr`. Subscribers receive all records up to the
+/// highest level requested by any subscriber.
+///
+/// Since records are forwarded using a broadcast channel, slow subscribers miss records
+/// instead of blocking the logging thread.
+struct Logger {
+  inner: env_logger::Logger,
+  tx: broadcast::Sender<(Level, String)>,
+}
+
+impl Logger {
+  fn is_forwarded(&self, metadata: &Metadata<'_>) -> bool {
+    self.tx.receiver_count() > 0
+      && metadata.level() as usize <= SUBSCRIBER_LEVEL.load(Ordering::Relaxed)
+      && !metadata.target().starts_with(EXCLUDED_TARGET)
+  }
+}
+
+impl Log for Logger {
+  fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+    self.inner.enabled(metadata) || self.is_forwarded(metadata)
+  }
+
+  fn log(&self, record: &Record<'_>) {
+    if self.inner.matches(record) {
+      self.inner.log(record);
+    }
+
+    if self.is_forwarded(record.metadata()) {
+      let _ = self.tx.send((record.level(), format!("[{}] {}", record.target(), record.args())));
+    }
+  }
+
+  fn flush(&self) {
+    self.inner.flush()
+  }
+}
+
+/// Initializes the global logger, configured using the `RUST_LOG` environment variable.
+pub fn init() {
+  let inner = env_logger::Builder::from_default_env().build();
+  let (tx, _) = broadcast::channel(CAPACITY);
+
+  RECORDS.set(tx.clone()).expect("Logger already initialized");
+  STDERR_LEVEL.set(inner.filter()).expect("Logger already initialized");
+
+  log::set_max_level(inner.filter());
+  log::set_boxed_logger(Box::new(Logger { inner, tx })).expect("Failed to initialize logger");
+}
+
+/// Sets the global maximum level to the highest level needed by `stderr` or any subscriber.
+fn update_max_level(subscriber_levels: &[LevelFilter]) {
+  let subscriber_level = subscriber_levels.iter().copied().max().unwrap_or(LevelFilter::Off);
+  SUBSCRIBER_LEVEL.store(subscriber_level as usize, Ordering::Relaxed);
+
+  let stderr_level = STDERR_LEVEL.get().copied().unwrap_or(LevelFilter::Off);
+  log::set_max_level(stderr_level.max(subscriber_level));
+}
+
+/// A subscription to log records, which stops raising the global maximum level once dropped.
+pub struct Subscription {
+  rx: broadcast::Receiver<(Level, String)>,
+  level: LevelFilter,
+}
+
+impl Subscription {
+  /// Receives the next log record, see [`broadcast::Receiver::recv`].
+  pub async fn recv(&mut self) -> Result<(Level, String), broadcast::error::RecvError> {
+    self.rx.recv().await
+  }
+}
+
+impl Drop for Subscription {
+  fn drop(&mut self) {
+    let mut levels = SUBSCRIBER_LEVELS.lock().unwrap();
+    if let Some(i) = levels.iter().position(|&level| level == self.level) {
+      levels.swap_remove(i);
+    }
+    update_max_level(&levels);
+  }
+}
+
+/// Subscribes to log records up to the given level, regardless of `RUST_LOG`.
+pub fn subscribe(level: LevelFilter) -> Subscription {
+  let rx = RECORDS.get().expect("Logger not initialized").subscribe();
+
+  let mut levels = SUBSCRIBER_LEVELS.lock().unwrap();
+  levels.push(level);
+  update_max_level(&levels);
+
+  Subscription { rx, level }
+}
@@ -1,4 +1,4 @@
-use std::{env, process, sync::Arc};
+use std::{env, process};
 
 use tokio::{
   signal::unix::{SignalKind, signal},
@@ -6,14 +6,41 @@ use tokio::{
 };
 use vcontrol::{self, Optolink, VControl};
 
-use crate::command_poller::poll_thread;
+use crate::{
+  clock_sync::clock_sync,
+  command_poller::{Subscriptions, poll_thread},
+  error_history::{ErrorHistory, error_history_loop},
+  time_zone::TimeZone,
+};
 
+mod bus;
+mod clock_sync;
 mod command_poller;
+mod error_history;
 mod esphome_server;
+mod logger;
+mod party_mode;
+mod schedule;
+mod time_zone;
+mod transaction;
+mod vacation;
 
 #[tokio::main]
 async fn main() {
-  env_logger::init();
+  logger::init();
+
+  let error_history_path = ErrorHistory::path_from_env();
+
+  if env::args().nth(1).as_deref() == Some("error-history") {
+    match ErrorHistory::load(&error_history_path) {
+      Ok(history) => print!("{history}"),
+      Err(err) => {
+        eprintln!("Failed to load error history from {}: {err}", error_history_path.display());
+        process::exit(1);
+      },
+    }
+    return;
+  }
 
   let optolink_device = env::var("OPTOLINK_DEVICE").unwrap_or_else(|_| "/dev/optolink".into());
 
@@ -24,12 +51,34 @@ async fn main() {
   };
   let vcontrol = VControl::connect(device).await.expect("Failed to connect to device");
 
+  let time_zone = TimeZone::from_env();
+  log::info!("Using time zone: {time_zone}");
+
+  let esphome_config = esphome_server::Config::from_env(vcontrol.device());
+  let device = vcontrol.device();
+
+  // Don't record anything if the history cannot be loaded, to avoid overwriting it.
+  let error_history = match ErrorHistory::load(&error_history_path) {
+    Ok(history) => Some(history),
+    Err(err) => {
+      log::error!("Failed to load error history from {}: {err}", error_history_path.display());
+      None
+    },
+  };
+
   let sigint = async { signal(SignalKind::interrupt()).unwrap().recv().await };
   let sigterm = async { signal(SignalKind::terminate()).unwrap().recv().await };
 
-  let (vcontrol, rx, poll_thread, commands) = poll_thread(vcontrol).await;
+  let subscriptions = Subscriptions::new();
+  let (bus, bus_task) = bus::start(vcontrol);
+  let (store, refresher, poll_thread, commands) = poll_thread(bus.clone(), subscriptions.clone()).await;
+  let error_history = error_history.map(|error_history| {
+    tokio::spawn(error_history_loop(store.updates(), subscriptions.clone(), error_history, device, time_zone))
+  });
   let (esphome_server, esphome_server_stop, esphome_server_stopped) =
-    esphome_server::start(Arc::downgrade(&vcontrol), commands.clone(), rx).await;
+    esphome_server::start(bus.clone(), commands.clone(), store, refresher, subscriptions, time_zone, esphome_config)
+      .await;
+  let clock_sync = tokio::spawn(clock_sync(bus, time_zone));
 
   let (poll_thread_stopped_tx, poll_thread_stopped) = oneshot::channel();
   let poll_thread = tokio::spawn(async {
@@ -50,7 +99,11 @@ async fn main() {
     _ = esphome_server_stopped => (),
     _ = poll_thread_stopped => (),
   }
-  drop(vcontrol);
+  bus_task.abort();
+  clock_sync.abort();
+  if let Some(error_history) = error_history {
+    error_history.abort();
+  }
 
   log::info!("Stopping ESPHome server.");
   esphome_server_stop.send(()).unwrap();
@@ -0,0 +1,126 @@
+use std::{collections::HashMap, fmt};
+
+use vcontrol::{Command, VControl, Value};
+
+use crate::transaction::{set_all, writable_command};
+
+/// Party duration command for each heating circuit.
+const CIRCUITS: [&str; 2] = ["KF2_KonfiPartyzeitA1M1", "KF2_KonfiPartyzeitM2"];
+
+/// Remaining party time. Party mode is started by setting it and stopped by resetting it to 0.
+///
+/// This is the only party timer of the controller, so it runs party mode for all heating circuits.
+pub const PARTY_TIMER: &str = "PartyTimerA1M1";
+
+/// Maximum party mode duration in hours supported by the controller.
+const MAX_HOURS: i32 = 12;
+
+#[derive(Debug)]
+pub enum PartyModeError {
+  InvalidCircuit(i32),
+  SharedTimer(i32),
+  InvalidDuration(i32),
+  VControl(vcontrol::Error),
+}
+
+impl From<vcontrol::Error> for PartyModeError {
+  fn from(err: vcontrol::Error) -> Self {
+    Self::VControl(err)
+  }
+}
+
+impl fmt::Display for PartyModeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::InvalidCircuit(circuit) => write!(f, "invalid heating circuit {circuit}"),
+      Self::SharedTimer(circuit) => {
+        write!(f, "cannot set party mode for heating circuit {circuit} only, the party timer is shared by all circuits")
+      },
+      Self::InvalidDuration(hours) => write!(f, "invalid duration of {hours} h, expected 0 to {MAX_HOURS} h"),
+      Self::VControl(err) => err.fmt(f),
+    }
+  }
+}
+
+/// Returns whether the party timer is running.
+///
+/// `vcontrol`'s device data limits the party timer to 0 h, so a running timer is read as [`Value::Empty`].
+pub fn is_active(timer: &Value) -> bool {
+  match timer {
+    Value::Int(n) => *n > 0,
+    Value::Double(n) => *n > 0.0,
+    _ => true,
+  }
+}
+
+/// Returns the party duration command of the given heating circuit (1 or 2).
+pub fn duration_command(circuit: i32) -> Result<&'static str, PartyModeError> {
+  match circuit {
+    1..=2 => Ok(CIRCUITS[circuit as usize - 1]),
+    circuit => Err(PartyModeError::InvalidCircuit(circuit)),
+  }
+}
+
+/// Returns whether the device supports party mode for at least one heating circuit.
+pub fn is_supported(commands: &HashMap<&str, &Command>) -> bool {
+  let is_writable = |command| commands.get(command).is_some_and(|command| command.access_mode().is_write());
+  is_writable(PARTY_TIMER) && CIRCUITS.iter().any(|command| is_writable(command))
+}
+
+/// Sets the party timer to the given number of hours.
+///
+/// Since `vcontrol`'s device data limits the party timer to 0 h, `VControl::set` rejects a running timer. Instead,
+/// the value is encoded in the same way as `vcontrol` does, i.e. in seconds as a little-endian 16-bit integer.
+async fn set_timer(vcontrol: &mut VControl, hours: i32) -> Result<(), vcontrol::Error> {
+  log::info!("Setting value for {PARTY_TIMER}: {hours} h");
+
+  let seconds = (hours * 60 * 60) as u16;
+  vcontrol.set_bytes(PARTY_TIMER, &seconds.to_le_bytes()).await
+}
+
+/// Turns on party mode for the given number of hours. A duration of 0 hours turns party mode off.
+///
+/// Since the party timer is shared by all heating circuits, `circuit` must be 0 (all). Selecting a single circuit is
+/// rejected, since starting the timer would start party mode for the other circuit as well.
+///
+/// The party duration of each circuit supported by the device is set before starting the party timer. If starting the
+/// timer fails, the previous durations are not restored, since they only take effect once party mode is started.
+pub async fn set_party_mode(vcontrol: &mut VControl, circuit: i32, hours: i32) -> Result<(), PartyModeError> {
+  match circuit {
+    0 => (),
+    1..=2 => return Err(PartyModeError::SharedTimer(circuit)),
+    circuit => return Err(PartyModeError::InvalidCircuit(circuit)),
+  }
+
+  if !(0..=MAX_HOURS).contains(&hours) {
+    return Err(PartyModeError::InvalidDuration(hours));
+  }
+
+  if hours > 0 {
+    let values = CIRCUITS
+      .into_iter()
+      .filter(|command| writable_command(vcontrol, command).is_ok())
+      .map(|command| (command, Value::Int(hours.into())))
+      .collect::<Vec<_>>();
+    set_all(vcontrol, &values).await?;
+  }
+
+  Ok(set_timer(vcontrol, hours).await?)
+}
+
+/// Turns on party mode for the duration configured on the controller for the given heating circuit (1 or 2).
+///
+/// The party timer is shared by all heating circuits, so this starts party mode for the other circuit as well.
+/// A configured duration of 0 h starts party mode for 1 h, since a timer of 0 h turns party mode off.
+pub async fn start(vcontrol: &mut VControl, circuit: i32) -> Result<(), PartyModeError> {
+  let command_name = duration_command(circuit)?;
+  let hours = match vcontrol.get(command_name).await?.value {
+    Value::Int(hours) => hours.clamp(1, MAX_HOURS.into()) as i32,
+    value => {
+      log::warn!("Unexpected party duration for {command_name}: {value:?}, using {MAX_HOURS} h");
+      MAX_HOURS
+    },
+  };
+
+  Ok(set_timer(vcontrol, hours).await?)
+}
@@ -0,0 +1,355 @@
+use std::{fmt, str::FromStr};
+
+use serde_json::json;
+use vcontrol::{Value, types::CircuitTimes};
+
+/// Maximum number of on/off periods per day supported by the controller.
+pub const MAX_PERIODS: usize = 4;
+
+/// Pattern of a time accepted by [`Time::from_str`].
+const TIME_PATTERN: &str = r"(?:(?:[01][0-9]|2[0-3]):[0-5]0|24:00)";
+
+/// Separator between the periods of a day. The space is optional when parsing.
+const PERIOD_SEPARATOR: &str = ", ";
+
+/// Maximum length of a day schedule in the syntax described by [`day_schedule_pattern`].
+pub const DAY_SCHEDULE_MAX_LEN: usize = MAX_PERIODS * "00:00-00:00".len() + (MAX_PERIODS - 1) * PERIOD_SEPARATOR.len();
+
+/// Returns a regular expression matching the syntax accepted by [`DaySchedule::from_str`], e.g.
+/// `06:00-08:00, 17:00-22:00`.
+pub fn day_schedule_pattern() -> String {
+  let period = format!("{TIME_PATTERN}-{TIME_PATTERN}");
+  format!("^(?:{period}(?:, ?{period}){{0,{}}})?$", MAX_PERIODS - 1)
+}
+
+/// Weekdays in the order used by the controller, as `(key, name)` pairs.
+pub const WEEKDAYS: [(&str, &str); 7] = [
+  ("mon", "Monday"),
+  ("tue", "Tuesday"),
+  ("wed", "Wednesday"),
+  ("thu", "Thursday"),
+  ("fri", "Friday"),
+  ("sat", "Saturday"),
+  ("sun", "Sunday"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleError {
+  InvalidTime(String),
+  InvalidPeriod(String),
+  TooManyPeriods(usize),
+  OverlappingPeriods(Period, Period),
+  InvalidValue(String),
+}
+
+impl fmt::Display for ScheduleError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::InvalidTime(time) => write!(f, "invalid time '{time}', expected HH:MM in steps of 10 minutes"),
+      Self::InvalidPeriod(period) => write!(f, "invalid period '{period}', expected HH:MM-HH:MM"),
+      Self::TooManyPeriods(count) => write!(f, "{count} periods given, at most {MAX_PERIODS} are supported"),
+      Self::OverlappingPeriods(a, b) => write!(f, "periods {a} and {b} overlap"),
+      Self::InvalidValue(description) => description.fmt(f),
+    }
+  }
+}
+
+/// A time of day with a resolution of 10 minutes, as stored by the controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Time {
+  hour: u8,
+  minute: u8,
+}
+
+impl FromStr for Time {
+  type Err = ScheduleError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || ScheduleError::InvalidTime(s.to_owned());
+
+    let (hour, minute) = s.split_once(':').ok_or_else(invalid)?;
+    if hour.len() != 2 || minute.len() != 2 {
+      return Err(invalid());
+    }
+
+    let hour = hour.parse::<u8>().map_err(|_| invalid())?;
+    let minute = minute.parse::<u8>().map_err(|_| invalid())?;
+
+    if hour > 24 || minute >= 60 || minute % 10 != 0 || (hour == 24 && minute != 0) {
+      return Err(invalid());
+    }
+
+    Ok(Self { hour, minute })
+  }
+}
+
+impl fmt::Display for Time {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:02}:{:02}", self.hour, self.minute)
+  }
+}
+
+/// A period during which a switching time program is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period {
+  pub from: Time,
+  pub to: Time,
+}
+
+impl FromStr for Period {
+  type Err = ScheduleError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (from, to) = s.split_once('-').ok_or_else(|| ScheduleError::InvalidPeriod(s.to_owned()))?;
+    let (from, to) = (from.parse()?, to.parse()?);
+
+    if from >= to {
+      return Err(ScheduleError::InvalidPeriod(s.to_owned()));
+    }
+
+    Ok(Self { from, to })
+  }
+}
+
+impl fmt::Display for Period {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}-{}", self.from, self.to)
+  }
+}
+
+/// The on/off periods of a single day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DaySchedule(Vec<Period>);
+
+impl DaySchedule {
+  /// Creates a day schedule, ensuring there are at most [`MAX_PERIODS`] non-overlapping periods.
+  pub fn new(mut periods: Vec<Period>) -> Result<Self, ScheduleError> {
+    if periods.len() > MAX_PERIODS {
+      return Err(ScheduleError::TooManyPeriods(periods.len()));
+    }
+
+    periods.sort_by_key(|period| period.from);
+
+    for pair in periods.windows(2) {
+      if pair[0].to > pair[1].from {
+        return Err(ScheduleError::OverlappingPeriods(pair[0], pair[1]));
+      }
+    }
+
+    Ok(Self(periods))
+  }
+}
+
+impl FromStr for DaySchedule {
+  type Err = ScheduleError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if s.is_empty() {
+      return Ok(Self::default());
+    }
+
+    let periods = s
+      .split(',')
+      .enumerate()
+      .map(|(i, period)| if i > 0 { period.strip_prefix(' ').unwrap_or(period) } else { period })
+      .map(str::parse)
+      .collect::<Result<_, _>>()?;
+
+    Self::new(periods)
+  }
+}
+
+impl fmt::Display for DaySchedule {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, period) in self.0.iter().enumerate() {
+      if i > 0 {
+        write!(f, "{PERIOD_SEPARATOR}")?;
+      }
+
+      write!(f, "{period}")?;
+    }
+
+    Ok(())
+  }
+}
+
+/// A weekly switching time program (“Schaltzeiten”).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schedule([DaySchedule; 7]);
+
+impl Schedule {
+  /// Returns the schedule for the given weekday, from 0 (Monday) to 6 (Sunday).
+  pub fn day(&self, weekday: usize) -> &DaySchedule {
+    &self.0[weekday]
+  }
+
+  /// Replaces the schedule for the given weekday, from 0 (Monday) to 6 (Sunday).
+  pub fn set_day(&mut self, weekday: usize, day: DaySchedule) {
+    self.0[weekday] = day;
+  }
+
+  pub fn days(&self) -> impl Iterator<Item = &DaySchedule> {
+    self.0.iter()
+  }
+
+  /// Converts the schedule into a value which can be written using `VControl::set`.
+  pub fn to_value(&self) -> Result<Value, ScheduleError> {
+    let mut object = serde_json::Map::new();
+
+    for ((key, _), day) in WEEKDAYS.iter().zip(&self.0) {
+      let periods = (0..MAX_PERIODS)
+        .map(|i| match day.0.get(i) {
+          Some(period) => json!({ "from": period.from.to_string(), "to": period.to.to_string() }),
+          None => serde_json::Value::Null,
+        })
+        .collect();
+
+      object.insert((*key).to_owned(), serde_json::Value::Array(periods));
+    }
+
+    let circuit_times = serde_json::from_value::<CircuitTimes>(serde_json::Value::Object(object))
+      .map_err(|err| ScheduleError::InvalidValue(err.to_string()))?;
+
+    Ok(Value::CircuitTimes(Box::new(circuit_times)))
+  }
+}
+
+impl TryFrom<&CircuitTimes> for Schedule {
+  type Error = ScheduleError;
+
+  fn try_from(circuit_times: &CircuitTimes) -> Result<Self, Self::Error> {
+    let invalid = || ScheduleError::InvalidValue(format!("unsupported circuit times: {circuit_times:?}"));
+
+    let object = serde_json::to_value(circuit_times).map_err(|err| ScheduleError::InvalidValue(err.to_string()))?;
+
+    let mut schedule = Self::default();
+
+    for (weekday, (key, _)) in WEEKDAYS.iter().enumerate() {
+      let periods = object.get(key).and_then(|periods| periods.as_array()).ok_or_else(invalid)?;
+
+      let periods = periods
+        .iter()
+        .filter(|period| !period.is_null())
+        .map(|period| {
+          let from = period.get("from").and_then(|from| from.as_str()).ok_or_else(invalid)?;
+          let to = period.get("to").and_then(|to| to.as_str()).ok_or_else(invalid)?;
+          Ok(Period { from: from.parse()?, to: to.parse()? })
+        })
+        .collect::<Result<_, ScheduleError>>()?;
+
+      // Periods read from the controller are not validated further, so they are shown even if they overlap.
+      schedule.0[weekday] = DaySchedule(periods);
+    }
+
+    Ok(schedule)
+  }
+}
+
+impl TryFrom<&Value> for Schedule {
+  type Error = ScheduleError;
+
+  fn try_from(value: &Value) -> Result<Self, Self::Error> {
+    match value {
+      Value::CircuitTimes(circuit_times) => Self::try_from(circuit_times.as_ref()),
+      Value::Empty => Ok(Self::default()),
+      value => Err(ScheduleError::InvalidValue(format!("expected circuit times, got {value:?}"))),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use regex::Regex;
+
+  use super::*;
+
+  fn period(from: &str, to: &str) -> Period {
+    Period { from: from.parse().unwrap(), to: to.parse().unwrap() }
+  }
+
+  #[test]
+  fn accepts_24_00_as_end_time() {
+    assert_eq!("22:00-24:00".parse(), Ok(period("22:00", "24:00")));
+    assert_eq!("24:00".parse::<Time>().unwrap().to_string(), "24:00");
+
+    assert_eq!("24:10".parse::<Time>(), Err(ScheduleError::InvalidTime("24:10".into())));
+    assert_eq!("24:00-24:00".parse::<Period>(), Err(ScheduleError::InvalidPeriod("24:00-24:00".into())));
+  }
+
+  #[test]
+  fn rejects_times_not_on_10_minute_step() {
+    for time in ["06:05", "06:59", "06:60", "6:00", "06:0", "25:00", " 06:00"] {
+      assert_eq!(time.parse::<Time>(), Err(ScheduleError::InvalidTime(time.into())), "{time}");
+    }
+
+    assert_eq!("06:00".parse::<Time>().unwrap().to_string(), "06:00");
+  }
+
+  #[test]
+  fn rejects_overlapping_periods() {
+    assert_eq!(
+      "06:00-08:00, 07:50-09:00".parse::<DaySchedule>(),
+      Err(ScheduleError::OverlappingPeriods(period("06:00", "08:00"), period("07:50", "09:00")))
+    );
+
+    // Periods are sorted, and adjacent periods do not overlap.
+    let day = "17:00-22:00, 06:00-08:00, 08:00-09:00".parse::<DaySchedule>().unwrap();
+    assert_eq!(day.to_string(), "06:00-08:00, 08:00-09:00, 17:00-22:00");
+  }
+
+  #[test]
+  fn rejects_more_than_4_periods() {
+    let day = "00:00-01:00, 02:00-03:00, 04:00-05:00, 06:00-07:00";
+    assert_eq!(day.parse::<DaySchedule>().unwrap().to_string(), day);
+
+    assert_eq!(
+      format!("{day}, 08:00-09:00").parse::<DaySchedule>(),
+      Err(ScheduleError::TooManyPeriods(MAX_PERIODS + 1))
+    );
+  }
+
+  #[test]
+  fn pattern_matches_parser() {
+    let pattern = Regex::new(&day_schedule_pattern()).unwrap();
+
+    let valid = ["", "06:00-08:00", "06:00-08:00,17:00-24:00", "00:00-01:00, 02:00-03:00, 04:00-05:00, 23:50-24:00"];
+    for day in valid {
+      assert!(pattern.is_match(day), "{day}");
+      assert!(day.parse::<DaySchedule>().is_ok(), "{day}");
+    }
+
+    let invalid = [
+      " ",
+      "06:00 - 08:00",
+      "06:00–08:00",
+      "06:00-08:00,",
+      "06:00-08:00,  17:00-22:00",
+      "06:05-08:00",
+      "06:00-24:10",
+      "00:00-01:00, 02:00-03:00, 04:00-05:00, 06:00-07:00, 08:00-09:00",
+    ];
+    for day in invalid {
+      assert!(!pattern.is_match(day), "{day}");
+      assert!(day.parse::<DaySchedule>().is_err(), "{day}");
+    }
+  }
+
+  #[test]
+  fn max_len_fits_longest_schedule() {
+    let day = "00:00-01:00, 02:00-03:00, 04:00-05:00, 06:00-24:00";
+    assert_eq!(day.len(), DAY_SCHEDULE_MAX_LEN);
+    assert_eq!(day.parse::<DaySchedule>().unwrap().to_string(), day);
+  }
+
+  #[test]
+  fn circuit_times_round_trip() {
+    let mut schedule = Schedule::default();
+    schedule.set_day(0, "06:00-08:00, 17:00-22:00".parse().unwrap());
+    schedule.set_day(5, "08:00-24:00".parse().unwrap());
+    schedule.set_day(6, "00:00-01:00, 02:00-03:00, 04:00-05:00, 06:00-07:00".parse().unwrap());
+
+    let value = schedule.to_value().unwrap();
+    assert!(matches!(value, Value::CircuitTimes(_)));
+    assert_eq!(Schedule::try_from(&value), Ok(schedule));
+  }
+}
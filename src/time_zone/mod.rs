@@ -0,0 +1,134 @@
+use std::{env, fmt};
+
+use chrono::{Datelike, Local, LocalResult, NaiveDate, NaiveDateTime, TimeDelta, Timelike, Utc};
+use chrono_tz::Tz;
+use vcontrol::types::DateTime;
+
+/// The time zone in which the controller stores its wall-clock time.
+#[derive(Debug, Clone, Copy)]
+pub enum TimeZone {
+  /// The system time zone.
+  Local,
+  /// A time zone from the IANA time zone database.
+  Iana(Tz),
+}
+
+impl TimeZone {
+  /// Reads the time zone from the `TZ` environment variable, falling back to the system time zone.
+  pub fn from_env() -> Self {
+    let Ok(tz) = env::var("TZ") else { return Self::Local };
+
+    // A leading colon is allowed by POSIX to denote an implementation-defined time zone name.
+    let tz = tz.strip_prefix(':').unwrap_or(&tz);
+    if tz.is_empty() {
+      return Self::Local;
+    }
+
+    match tz.parse::<Tz>() {
+      Ok(tz) => Self::Iana(tz),
+      Err(err) => {
+        log::warn!("Invalid time zone '{tz}', using system time zone: {err}");
+        Self::Local
+      },
+    }
+  }
+
+  fn resolve<T: chrono::TimeZone>(tz: &T, date_time: &NaiveDateTime) -> Option<i64> {
+    match tz.from_local_datetime(date_time) {
+      LocalResult::Single(date_time) => Some(date_time.timestamp()),
+      // Within the repeated hour when DST ends, use the earlier point in time.
+      LocalResult::Ambiguous(earliest, _) => Some(earliest.timestamp()),
+      // Within the skipped hour when DST starts, use the offset from before the transition.
+      LocalResult::None => match tz.from_local_datetime(&(*date_time - TimeDelta::hours(1))) {
+        LocalResult::Single(date_time) | LocalResult::Ambiguous(date_time, _) => Some(date_time.timestamp() + 3600),
+        LocalResult::None => None,
+      },
+    }
+  }
+
+  /// Converts a wall-clock date-time in this time zone to a unix timestamp.
+  pub fn unix_timestamp(&self, date_time: &DateTime) -> Option<i64> {
+    let date_time = NaiveDate::from_ymd_opt(date_time.year().into(), date_time.month().into(), date_time.day().into())?
+      .and_hms_opt(date_time.hour().into(), date_time.minute().into(), date_time.second().into())?;
+
+    match self {
+      Self::Local => Self::resolve(&Local, &date_time),
+      Self::Iana(tz) => Self::resolve(tz, &date_time),
+    }
+  }
+
+  /// Converts a unix timestamp to a wall-clock date-time in this time zone.
+  pub fn date_time(&self, timestamp: i64) -> Option<DateTime> {
+    let date_time = chrono::DateTime::<Utc>::from_timestamp(timestamp, 0)?;
+
+    let date_time = match self {
+      Self::Local => date_time.with_timezone(&Local).naive_local(),
+      Self::Iana(tz) => date_time.with_timezone(tz).naive_local(),
+    };
+
+    DateTime::new(
+      u16::try_from(date_time.year()).ok()?,
+      date_time.month() as u8,
+      date_time.day() as u8,
+      date_time.hour() as u8,
+      date_time.minute() as u8,
+      date_time.second() as u8,
+    )
+    .ok()
+  }
+
+  /// Returns the current wall-clock date-time in this time zone.
+  pub fn now(&self) -> DateTime {
+    self.date_time(Utc::now().timestamp()).expect("current time is always valid")
+  }
+}
+
+impl fmt::Display for TimeZone {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Local => "system time zone".fmt(f),
+      Self::Iana(tz) => tz.name().fmt(f),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::TimeZone as _;
+  use chrono_tz::Europe;
+
+  use super::*;
+
+  const VIENNA: TimeZone = TimeZone::Iana(Europe::Vienna);
+
+  fn date_time(month: u8, day: u8, hour: u8, minute: u8) -> DateTime {
+    DateTime::new(2025, month, day, hour, minute, 0).unwrap()
+  }
+
+  fn utc_timestamp(month: u32, day: u32, hour: u32, minute: u32) -> i64 {
+    Utc.with_ymd_and_hms(2025, month, day, hour, minute, 0).unwrap().timestamp()
+  }
+
+  #[test]
+  fn skipped_hour_uses_offset_from_before_transition() {
+    // On 2025-03-30, clocks in Vienna go from 02:00 CET to 03:00 CEST.
+    assert_eq!(VIENNA.unix_timestamp(&date_time(3, 30, 1, 30)), Some(utc_timestamp(3, 30, 0, 30)));
+    assert_eq!(VIENNA.unix_timestamp(&date_time(3, 30, 2, 30)), Some(utc_timestamp(3, 30, 1, 30)));
+    assert_eq!(VIENNA.unix_timestamp(&date_time(3, 30, 3, 30)), Some(utc_timestamp(3, 30, 1, 30)));
+
+    // The skipped hour never occurs when converting back.
+    assert_eq!(VIENNA.date_time(utc_timestamp(3, 30, 1, 30)), Some(date_time(3, 30, 3, 30)));
+  }
+
+  #[test]
+  fn repeated_hour_resolves_to_earliest_instant() {
+    // On 2025-10-26, clocks in Vienna go from 03:00 CEST back to 02:00 CET.
+    assert_eq!(VIENNA.unix_timestamp(&date_time(10, 26, 1, 30)), Some(utc_timestamp(10, 25, 23, 30)));
+    assert_eq!(VIENNA.unix_timestamp(&date_time(10, 26, 2, 30)), Some(utc_timestamp(10, 26, 0, 30)));
+    assert_eq!(VIENNA.unix_timestamp(&date_time(10, 26, 3, 30)), Some(utc_timestamp(10, 26, 2, 30)));
+
+    // Both instants within the repeated hour show the same wall-clock time.
+    assert_eq!(VIENNA.date_time(utc_timestamp(10, 26, 0, 30)), Some(date_time(10, 26, 2, 30)));
+    assert_eq!(VIENNA.date_time(utc_timestamp(10, 26, 1, 30)), Some(date_time(10, 26, 2, 30)));
+  }
+}
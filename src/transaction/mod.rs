@@ -0,0 +1,55 @@
+use vcontrol::{Command, Error, VControl, Value};
+
+/// Looks up a device or system command by name, ensuring it can be written.
+pub fn writable_command(vcontrol: &VControl, command_name: &str) -> Result<&'static Command, Error> {
+  match vcontrol::commands::system_command(command_name).or_else(|| vcontrol.device().command(command_name)) {
+    Some(command) if command.access_mode().is_write() => Ok(command),
+    Some(_) => Err(Error::UnsupportedMode(format!("{command_name} does not support writing."))),
+    None => Err(Error::UnsupportedCommand(command_name.to_owned())),
+  }
+}
+
+/// Writes a single value.
+///
+/// The controller marks unset values by filling them with `0xFF`, which `vcontrol` reads as [`Value::Empty`].
+/// Since `vcontrol` cannot serialize an empty value, these bytes are written directly.
+async fn set(vcontrol: &mut VControl, command_name: &str, value: Value) -> Result<(), Error> {
+  let Value::Empty = value else { return vcontrol.set(command_name, value).await };
+
+  let command = writable_command(vcontrol, command_name)?;
+  vcontrol.set_bytes(command_name, &vec![0xFF; command.block_len()]).await
+}
+
+/// Writes all values in order, restoring the previous values if any write fails.
+///
+/// All commands are checked to be writable before anything is written.
+pub async fn set_all(vcontrol: &mut VControl, values: &[(&str, Value)]) -> Result<(), Error> {
+  for (command_name, _) in values {
+    writable_command(vcontrol, command_name)?;
+  }
+
+  let mut previous = Vec::with_capacity(values.len());
+  for (command_name, _) in values {
+    previous.push(vcontrol.get(command_name).await?.value);
+  }
+
+  for (i, (command_name, value)) in values.iter().enumerate() {
+    log::info!("Setting value for {command_name}: {value:?}");
+
+    if let Err(err) = set(vcontrol, command_name, value.clone()).await {
+      log::error!("Failed to set value ({value:?}) for {command_name}: {err}");
+
+      for ((command_name, _), value) in values[..i].iter().zip(&previous).rev() {
+        log::info!("Restoring value for {command_name}: {value:?}");
+
+        if let Err(err) = set(vcontrol, command_name, value.clone()).await {
+          log::error!("Failed to restore value ({value:?}) for {command_name}: {err}");
+        }
+      }
+
+      return Err(err);
+    }
+  }
+
+  Ok(())
+}
@@ -0,0 +1,95 @@
+use std::{collections::HashMap, fmt};
+
+use vcontrol::{Command, VControl, Value, types::Date};
+
+use crate::transaction::{set_all, writable_command};
+
+/// Vacation begin and end commands for each heating circuit.
+const CIRCUITS: [(&str, &str); 2] = [
+  ("Ecotronic_FerienBeginn_HK1", "Ecotronic_FerienEnde_HK1"),
+  ("Ecotronic_FerienBeginn_HK2", "Ecotronic_FerienEnde_HK2"),
+];
+
+#[derive(Debug)]
+pub enum VacationError {
+  InvalidCircuit(i32),
+  InvalidDate(String),
+  InvalidRange(Date, Date),
+  VControl(vcontrol::Error),
+}
+
+impl From<vcontrol::Error> for VacationError {
+  fn from(err: vcontrol::Error) -> Self {
+    Self::VControl(err)
+  }
+}
+
+impl fmt::Display for VacationError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::InvalidCircuit(circuit) => write!(f, "invalid heating circuit {circuit}, expected 0 (all), 1 or 2"),
+      Self::InvalidDate(date) => write!(f, "invalid date '{date}', expected YYYY-MM-DD"),
+      Self::InvalidRange(begin, end) => write!(f, "vacation begin {begin} is after vacation end {end}"),
+      Self::VControl(err) => err.fmt(f),
+    }
+  }
+}
+
+/// Parses a date in `YYYY-MM-DD` format.
+pub fn parse_date(date: &str) -> Result<Date, VacationError> {
+  date.trim().parse().map_err(|()| VacationError::InvalidDate(date.to_owned()))
+}
+
+/// Returns the begin and end commands for the given heating circuit, or for all circuits supported by the device if
+/// `circuit` is 0.
+fn circuits(vcontrol: &VControl, circuit: i32) -> Result<Vec<(&'static str, &'static str)>, VacationError> {
+  match circuit {
+    0 => Ok(
+      CIRCUITS
+        .into_iter()
+        .filter(|&(begin_command, end_command)| {
+          writable_command(vcontrol, begin_command).is_ok() && writable_command(vcontrol, end_command).is_ok()
+        })
+        .collect(),
+    ),
+    1..=2 => Ok(vec![CIRCUITS[circuit as usize - 1]]),
+    circuit => Err(VacationError::InvalidCircuit(circuit)),
+  }
+}
+
+/// Returns whether the device supports vacation periods for at least one heating circuit.
+pub fn is_supported(commands: &HashMap<&str, &Command>) -> bool {
+  CIRCUITS.iter().any(|(begin_command, end_command)| {
+    [begin_command, end_command]
+      .iter()
+      .all(|command| commands.get(*command).is_some_and(|command| command.access_mode().is_write()))
+  })
+}
+
+/// Sets the vacation period for the given heating circuit, or for all circuits supported by the device if
+/// `circuit` is 0.
+pub async fn set_vacation(vcontrol: &mut VControl, circuit: i32, begin: Date, end: Date) -> Result<(), VacationError> {
+  if (begin.year(), begin.month(), begin.day()) > (end.year(), end.month(), end.day()) {
+    return Err(VacationError::InvalidRange(begin, end));
+  }
+
+  let values = circuits(vcontrol, circuit)?
+    .into_iter()
+    .flat_map(|(begin_command, end_command)| [(begin_command, Value::Date(begin)), (end_command, Value::Date(end))])
+    .collect::<Vec<_>>();
+
+  Ok(set_all(vcontrol, &values).await?)
+}
+
+/// Cancels the vacation period for the given heating circuit, or for all circuits supported by the device if
+/// `circuit` is 0.
+///
+/// Both dates are reset to unset, which is how the controller stores them when no vacation is programmed.
+pub async fn cancel_vacation(vcontrol: &mut VControl, circuit: i32) -> Result<(), VacationError> {
+  let values = circuits(vcontrol, circuit)?
+    .into_iter()
+    .flat_map(|(begin_command, end_command)| [(begin_command, Value::Empty), (end_command, Value::Empty)])
+    .collect::<Vec<_>>();
+
+  Ok(set_all(vcontrol, &values).await?)
+}